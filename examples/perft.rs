@@ -0,0 +1,29 @@
+extern crate warp_square_engine;
+
+use std::{env, time::Instant};
+
+use warp_square_engine::game::Game;
+
+fn main() {
+    let depth = env::args()
+        .nth(1)
+        .and_then(|depth| depth.parse().ok())
+        .unwrap_or(3);
+
+    let mut game = Game::new();
+
+    let start = Instant::now();
+    let divide = game.perft_divide(depth);
+    let elapsed = start.elapsed();
+
+    for (piece_move, nodes) in divide.iter() {
+        println!("{}: {}", piece_move, nodes);
+    }
+
+    let nodes: u64 = divide.iter().map(|(_, nodes)| nodes).sum();
+
+    println!();
+    println!("Moves: {}", divide.len());
+    println!("Nodes: {}", nodes);
+    println!("Time: {:?}", elapsed);
+}
@@ -5,7 +5,7 @@ use warp_square_engine::game::Game;
 fn main() {
     let game = Game::new();
 
-    game.board.pieces.iter().for_each(|piece| {
+    game.board.pieces().for_each(|piece| {
         println!(
             "{:?}({:?}): {:?}",
             piece.piece_type,
@@ -0,0 +1,106 @@
+extern crate warp_square_engine;
+
+use std::{hint::black_box, time::Instant};
+
+use warp_square_engine::{attack_table, bit_board::BitBoard, square::Level};
+
+const PINS: [Level; 12] = [
+    Level::QL1,
+    Level::QL2,
+    Level::QL3,
+    Level::QL4,
+    Level::QL5,
+    Level::QL6,
+    Level::KL1,
+    Level::KL2,
+    Level::KL3,
+    Level::KL4,
+    Level::KL5,
+    Level::KL6,
+];
+
+/// 공격 보드 4개를 서로 다른 핀에 놓는 모든 배치에서 막힌 Square 집합을 만듭니다.
+fn generate_occupancies() -> Vec<BitBoard> {
+    let main_area = BitBoard::WHITE_SET | BitBoard::NEUTRAL_SET | BitBoard::BLACK_SET;
+    let mut seed: u64 = 0x2545_F491_4F6C_DD1D;
+    let mut occupancies = Vec::new();
+
+    for a in PINS {
+        for b in PINS.iter().filter(|pin| ![a].contains(pin)) {
+            for c in PINS.iter().filter(|pin| ![a, *b].contains(pin)) {
+                for d in PINS.iter().filter(|pin| ![a, *b, *c].contains(pin)) {
+                    let area = main_area
+                        | a.get_bit_board()
+                        | b.get_bit_board()
+                        | c.get_bit_board()
+                        | d.get_bit_board();
+
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+
+                    occupancies.push(BitBoard::from_bits_retain(seed) & area);
+                }
+            }
+        }
+    }
+
+    occupancies
+}
+
+fn ray_rook(square: BitBoard, occupied: BitBoard) -> BitBoard {
+    (square.ray(occupied, |current| current.down())
+        | square.ray(occupied, |current| current.up())
+        | square.ray(occupied, |current| current.left())
+        | square.ray(occupied, |current| current.right()))
+    .remove_level()
+}
+
+fn ray_bishop(square: BitBoard, occupied: BitBoard) -> BitBoard {
+    (square.ray(occupied, |current| current.down_left())
+        | square.ray(occupied, |current| current.down_right())
+        | square.ray(occupied, |current| current.up_left())
+        | square.ray(occupied, |current| current.up_right()))
+    .remove_level()
+}
+
+fn main() {
+    let occupancies = generate_occupancies();
+    let squares: Vec<BitBoard> = (0..60)
+        .map(|shift| BitBoard::from_bits_retain(1 << shift))
+        .collect();
+
+    let mut mismatches = 0;
+
+    for occupied in occupancies.iter() {
+        for square in squares.iter() {
+            if ray_rook(*square, *occupied) != attack_table::get_rook_attacks(*square, *occupied)
+                || ray_bishop(*square, *occupied)
+                    != attack_table::get_bishop_attacks(*square, *occupied)
+            {
+                mismatches += 1;
+            }
+        }
+    }
+
+    let start = Instant::now();
+    for occupied in occupancies.iter() {
+        for square in squares.iter() {
+            black_box(ray_rook(*square, *occupied) | ray_bishop(*square, *occupied));
+        }
+    }
+    let ray_elapsed = start.elapsed();
+
+    let start = Instant::now();
+    for occupied in occupancies.iter() {
+        for square in squares.iter() {
+            black_box(attack_table::get_queen_attacks(*square, *occupied));
+        }
+    }
+    let table_elapsed = start.elapsed();
+
+    println!("Layouts: {}", occupancies.len());
+    println!("Mismatches: {}", mismatches);
+    println!("Ray: {:?}", ray_elapsed);
+    println!("Table: {:?}", table_elapsed);
+}
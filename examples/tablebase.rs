@@ -0,0 +1,49 @@
+extern crate warp_square_engine;
+
+use std::{env, time::Instant};
+
+use warp_square_engine::{board::Board, piece::PieceType, square::Color, tablebase::Tablebase};
+
+/// 처음 배치의 공격 보드에서 테이블베이스를 만들어 저장합니다.
+///
+/// 두 번째 인자부터 킹을 뺀 기물 구성이며 대문자는 백, 소문자는 흑입니다. (예: Rn은 K+R 대 K+N)
+/// 구성을 주지 않으면 K+Q 대 K, K+R 대 K를 만듭니다.
+fn main() {
+    let path = env::args().nth(1).unwrap_or("tablebase.bin".to_string());
+    let mut piece_sets: Vec<String> = env::args().skip(2).collect();
+
+    if piece_sets.is_empty() {
+        piece_sets = vec![
+            "Q".to_string(),
+            "q".to_string(),
+            "R".to_string(),
+            "r".to_string(),
+        ];
+    }
+
+    let mut tablebase = Tablebase::new(Board::new().board_set);
+
+    for piece_set in piece_sets {
+        let pieces: Vec<(Color, PieceType)> = piece_set
+            .chars()
+            .map(|c| {
+                let color = match c.is_ascii_uppercase() {
+                    true => Color::White,
+                    false => Color::Black,
+                };
+
+                (color, PieceType::from_char(c).expect("Invalid piece"))
+            })
+            .collect();
+
+        let start = Instant::now();
+
+        tablebase.generate(&pieces).unwrap();
+
+        println!("{}: {:?}", piece_set, start.elapsed());
+    }
+
+    tablebase.save(&path).unwrap();
+
+    println!("Saved to {}", path);
+}
@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class BoardMoveBackwardException extends WarpSquareException {
+    public BoardMoveBackwardException(String message) {
+        super(message);
+    }
+}
@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class BoardOverloadedException extends WarpSquareException {
+    public BoardOverloadedException(String message) {
+        super(message);
+    }
+}
@@ -0,0 +1,87 @@
+// Automatically generated by flapigen
+package club.gamza.warpsquare.engine;
+
+
+public final class Evaluation {
+
+    private Evaluation() {}
+
+    public final int getMaterial() {
+        int ret = do_getMaterial(mNativeObj);
+
+        return ret;
+    }
+    private static native int do_getMaterial(long self);
+
+    public final int getNeutralControl() {
+        int ret = do_getNeutralControl(mNativeObj);
+
+        return ret;
+    }
+    private static native int do_getNeutralControl(long self);
+
+    public final int getMobility() {
+        int ret = do_getMobility(mNativeObj);
+
+        return ret;
+    }
+    private static native int do_getMobility(long self);
+
+    public final int getAttackBoardSafety() {
+        int ret = do_getAttackBoardSafety(mNativeObj);
+
+        return ret;
+    }
+    private static native int do_getAttackBoardSafety(long self);
+
+    public final int getKingExposure() {
+        int ret = do_getKingExposure(mNativeObj);
+
+        return ret;
+    }
+    private static native int do_getKingExposure(long self);
+
+    public final int getPawnStructure() {
+        int ret = do_getPawnStructure(mNativeObj);
+
+        return ret;
+    }
+    private static native int do_getPawnStructure(long self);
+
+    public final int getTotal() {
+        int ret = do_getTotal(mNativeObj);
+
+        return ret;
+    }
+    private static native int do_getTotal(long self);
+
+    public synchronized void delete() {
+        if (mNativeObj != 0) {
+            do_delete(mNativeObj);
+            mNativeObj = 0;
+       }
+    }
+    @Override
+    protected void finalize() throws Throwable {
+        try {
+            delete();
+        }
+        finally {
+             super.finalize();
+        }
+    }
+    private static native void do_delete(long me);
+    /*package*/ Evaluation(InternalPointerMarker marker, long ptr) {
+        assert marker == InternalPointerMarker.RAW_PTR;
+        this.mNativeObj = ptr;
+    }
+    /*package*/ long mNativeObj;
+
+    static {
+        try {
+            NativeUtils.loadLibraryFromJar();
+        } catch (java.io.IOException e) {
+            e.printStackTrace();
+        }
+    }
+}
\ No newline at end of file
@@ -31,6 +31,68 @@ public final class Game {
     }
     private static native boolean do_legalMove(long self, long a0);
 
+    public final PieceMove [] legalMoves() {
+        PieceMove [] ret = do_legalMoves(mNativeObj);
+
+        return ret;
+    }
+    private static native PieceMove [] do_legalMoves(long self);
+
+    public final boolean isCheck() {
+        boolean ret = do_isCheck(mNativeObj);
+
+        return ret;
+    }
+    private static native boolean do_isCheck(long self);
+
+    public final boolean isCheckmate() {
+        boolean ret = do_isCheckmate(mNativeObj);
+
+        return ret;
+    }
+    private static native boolean do_isCheckmate(long self);
+
+    public final boolean isStalemate() {
+        boolean ret = do_isStalemate(mNativeObj);
+
+        return ret;
+    }
+    private static native boolean do_isStalemate(long self);
+
+    public final boolean isDraw() {
+        boolean ret = do_isDraw(mNativeObj);
+
+        return ret;
+    }
+    private static native boolean do_isDraw(long self);
+
+    public final boolean canClaimDraw() {
+        boolean ret = do_canClaimDraw(mNativeObj);
+
+        return ret;
+    }
+    private static native boolean do_canClaimDraw(long self);
+
+    public final boolean isGameOver() {
+        boolean ret = do_isGameOver(mNativeObj);
+
+        return ret;
+    }
+    private static native boolean do_isGameOver(long self);
+
+    public final java.util.Optional<Color> getWinner() {
+        int ret = do_getWinner(mNativeObj);
+        java.util.Optional<Color> convRet;
+        if (ret != -1) {
+            convRet = java.util.Optional.of(Color.fromInt(ret));
+        } else {
+            convRet = java.util.Optional.empty();
+        }
+
+        return convRet;
+    }
+    private static native int do_getWinner(long self);
+
     public final void pushMove(PieceMove a0) throws Exception {
         long a00 = a0.mNativeObj;
         a0.mNativeObj = 0;
@@ -62,6 +124,20 @@ public final class Game {
     }
     private static native int do_getTurn(long self);
 
+    public final long getHalfmoveClock() {
+        long ret = do_getHalfmoveClock(mNativeObj);
+
+        return ret;
+    }
+    private static native long do_getHalfmoveClock(long self);
+
+    public final long getFullmoveNumber() {
+        long ret = do_getFullmoveNumber(mNativeObj);
+
+        return ret;
+    }
+    private static native long do_getFullmoveNumber(long self);
+
     public final Piece [] getPieces() {
         Piece [] ret = do_getPieces(mNativeObj);
 
@@ -76,6 +152,61 @@ public final class Game {
     }
     private static native Piece [] do_getCapturedPieces(long self);
 
+    public final Evaluation evaluate() {
+        long ret = do_evaluate(mNativeObj);
+        Evaluation convRet = new Evaluation(InternalPointerMarker.RAW_PTR, ret);
+
+        return convRet;
+    }
+    private static native long do_evaluate(long self);
+
+    public final void loadTablebase(String path) throws Exception {
+        do_loadTablebase(mNativeObj, path);
+    }
+    private static native void do_loadTablebase(long self, String path) throws Exception;
+
+    public final java.util.Optional<TablebaseEntry> probeTablebase() {
+        long ret = do_probeTablebase(mNativeObj);
+        java.util.Optional<TablebaseEntry> convRet;
+        if (ret != 0) {
+            convRet = java.util.Optional.of(new TablebaseEntry(InternalPointerMarker.RAW_PTR, ret));
+        } else {
+            convRet = java.util.Optional.empty();
+        }
+
+        return convRet;
+    }
+    private static native long do_probeTablebase(long self);
+
+    public final java.util.Optional<PieceMove> getBookMove(OpeningBook book, long random) {
+        long a0 = book.mNativeObj;
+        long ret = do_getBookMove(mNativeObj, a0, random);
+        java.util.Optional<PieceMove> convRet;
+        if (ret != 0) {
+            convRet = java.util.Optional.of(new PieceMove(InternalPointerMarker.RAW_PTR, ret));
+        } else {
+            convRet = java.util.Optional.empty();
+        }
+
+        JNIReachabilityFence.reachabilityFence1(book);
+
+        return convRet;
+    }
+    private static native long do_getBookMove(long self, long book, long random);
+
+    public final java.util.Optional<PieceMove> findBestMove(long depth, long time_millis, long threads) {
+        long ret = do_findBestMove(mNativeObj, depth, time_millis, threads);
+        java.util.Optional<PieceMove> convRet;
+        if (ret != 0) {
+            convRet = java.util.Optional.of(new PieceMove(InternalPointerMarker.RAW_PTR, ret));
+        } else {
+            convRet = java.util.Optional.empty();
+        }
+
+        return convRet;
+    }
+    private static native long do_findBestMove(long self, long depth, long time_millis, long threads);
+
     public synchronized void delete() {
         if (mNativeObj != 0) {
             do_delete(mNativeObj);
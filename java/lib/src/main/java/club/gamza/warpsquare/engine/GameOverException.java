@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class GameOverException extends WarpSquareException {
+    public GameOverException(String message) {
+        super(message);
+    }
+}
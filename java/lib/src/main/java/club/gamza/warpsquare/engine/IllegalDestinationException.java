@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class IllegalDestinationException extends WarpSquareException {
+    public IllegalDestinationException(String message) {
+        super(message);
+    }
+}
@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class InvalidNotationException extends WarpSquareException {
+    public InvalidNotationException(String message) {
+        super(message);
+    }
+}
@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class InvalidPromotionException extends WarpSquareException {
+    public InvalidPromotionException(String message) {
+        super(message);
+    }
+}
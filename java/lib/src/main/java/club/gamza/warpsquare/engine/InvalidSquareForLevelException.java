@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class InvalidSquareForLevelException extends WarpSquareException {
+    public InvalidSquareForLevelException(String message) {
+        super(message);
+    }
+}
@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class KingInCheckException extends WarpSquareException {
+    public KingInCheckException(String message) {
+        super(message);
+    }
+}
@@ -0,0 +1,64 @@
+// Automatically generated by flapigen
+package club.gamza.warpsquare.engine;
+
+
+public final class Mcts {
+
+    public Mcts(long iterations, long time_millis, double exploration, PlayoutPolicy policy, long max_playout_ply) {
+        int a3 = policy.getValue();
+        mNativeObj = init(iterations, time_millis, exploration, a3, max_playout_ply);
+        JNIReachabilityFence.reachabilityFence1(policy);
+    }
+    private static native long init(long iterations, long time_millis, double exploration, int policy, long max_playout_ply);
+
+    public final java.util.Optional<PieceMove> chooseMove(Game game) {
+        long a0 = game.mNativeObj;
+        long ret = do_chooseMove(mNativeObj, a0);
+        java.util.Optional<PieceMove> convRet;
+        if (ret != 0) {
+            convRet = java.util.Optional.of(new PieceMove(InternalPointerMarker.RAW_PTR, ret));
+        } else {
+            convRet = java.util.Optional.empty();
+        }
+
+        JNIReachabilityFence.reachabilityFence1(game);
+
+        return convRet;
+    }
+    private static native long do_chooseMove(long self, long game);
+
+    public final void clear() {
+        do_clear(mNativeObj);
+    }
+    private static native void do_clear(long self);
+
+    public synchronized void delete() {
+        if (mNativeObj != 0) {
+            do_delete(mNativeObj);
+            mNativeObj = 0;
+       }
+    }
+    @Override
+    protected void finalize() throws Throwable {
+        try {
+            delete();
+        }
+        finally {
+             super.finalize();
+        }
+    }
+    private static native void do_delete(long me);
+    /*package*/ Mcts(InternalPointerMarker marker, long ptr) {
+        assert marker == InternalPointerMarker.RAW_PTR;
+        this.mNativeObj = ptr;
+    }
+    /*package*/ long mNativeObj;
+
+    static {
+        try {
+            NativeUtils.loadLibraryFromJar();
+        } catch (java.io.IOException e) {
+            e.printStackTrace();
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+// Automatically generated by flapigen
+package club.gamza.warpsquare.engine;
+
+
+public enum MoveType {
+    Move(0),
+    BoardMove(1);
+
+    private final int value;
+    MoveType(int value) {
+        this.value = value;
+    }
+    public final int getValue() { return value; }
+    /*package*/ static MoveType fromInt(int x) {
+        switch (x) {
+            case 0: return Move;
+            case 1: return BoardMove;
+            default: throw new Error("Invalid value for enum MoveType: " + x);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class NoAttackBoardException extends WarpSquareException {
+    public NoAttackBoardException(String message) {
+        super(message);
+    }
+}
@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class NoPieceAtSourceException extends WarpSquareException {
+    public NoPieceAtSourceException(String message) {
+        super(message);
+    }
+}
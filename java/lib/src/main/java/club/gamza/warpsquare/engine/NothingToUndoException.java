@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class NothingToUndoException extends WarpSquareException {
+    public NothingToUndoException(String message) {
+        super(message);
+    }
+}
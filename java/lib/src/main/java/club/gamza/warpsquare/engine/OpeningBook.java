@@ -0,0 +1,53 @@
+// Automatically generated by flapigen
+package club.gamza.warpsquare.engine;
+
+
+public final class OpeningBook {
+
+    private OpeningBook() {}
+
+    public static OpeningBook load(String path) throws Exception {
+        long ret = do_load(path);
+        OpeningBook convRet = new OpeningBook(InternalPointerMarker.RAW_PTR, ret);
+
+        return convRet;
+    }
+    private static native long do_load(String path) throws Exception;
+
+    public final long getSize() {
+        long ret = do_getSize(mNativeObj);
+
+        return ret;
+    }
+    private static native long do_getSize(long self);
+
+    public synchronized void delete() {
+        if (mNativeObj != 0) {
+            do_delete(mNativeObj);
+            mNativeObj = 0;
+       }
+    }
+    @Override
+    protected void finalize() throws Throwable {
+        try {
+            delete();
+        }
+        finally {
+             super.finalize();
+        }
+    }
+    private static native void do_delete(long me);
+    /*package*/ OpeningBook(InternalPointerMarker marker, long ptr) {
+        assert marker == InternalPointerMarker.RAW_PTR;
+        this.mNativeObj = ptr;
+    }
+    /*package*/ long mNativeObj;
+
+    static {
+        try {
+            NativeUtils.loadLibraryFromJar();
+        } catch (java.io.IOException e) {
+            e.printStackTrace();
+        }
+    }
+}
\ No newline at end of file
@@ -34,6 +34,38 @@ public final class PieceMove {
     }
     private static native long do_getDestination(long self);
 
+    public final java.util.Optional<PieceType> getPromotion() {
+        int ret = do_getPromotion(mNativeObj);
+        java.util.Optional<PieceType> convRet;
+        if (ret != -1) {
+            convRet = java.util.Optional.of(PieceType.fromInt(ret));
+        } else {
+            convRet = java.util.Optional.empty();
+        }
+
+        return convRet;
+    }
+    private static native int do_getPromotion(long self);
+
+    public final MoveType getMoveType() {
+        int ret = do_getMoveType(mNativeObj);
+        MoveType convRet = MoveType.fromInt(ret);
+
+        return convRet;
+    }
+    private static native int do_getMoveType(long self);
+
+    public static PieceMove newBoardMove(Level source, Level destination) {
+        int a0 = source.getValue();        int a1 = destination.getValue();
+        long ret = do_newBoardMove(a0, a1);
+        PieceMove convRet = new PieceMove(InternalPointerMarker.RAW_PTR, ret);
+
+        JNIReachabilityFence.reachabilityFence2(source, destination);
+
+        return convRet;
+    }
+    private static native long do_newBoardMove(int source, int destination);
+
     public synchronized void delete() {
         if (mNativeObj != 0) {
             do_delete(mNativeObj);
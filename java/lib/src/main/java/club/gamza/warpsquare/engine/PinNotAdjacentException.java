@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class PinNotAdjacentException extends WarpSquareException {
+    public PinNotAdjacentException(String message) {
+        super(message);
+    }
+}
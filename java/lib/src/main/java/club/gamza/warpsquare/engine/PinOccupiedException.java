@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class PinOccupiedException extends WarpSquareException {
+    public PinOccupiedException(String message) {
+        super(message);
+    }
+}
@@ -0,0 +1,23 @@
+// Automatically generated by flapigen
+package club.gamza.warpsquare.engine;
+
+
+public enum PlayoutPolicy {
+    Random(0),
+    CaptureFirst(1),
+    Evaluation(2);
+
+    private final int value;
+    PlayoutPolicy(int value) {
+        this.value = value;
+    }
+    public final int getValue() { return value; }
+    /*package*/ static PlayoutPolicy fromInt(int x) {
+        switch (x) {
+            case 0: return Random;
+            case 1: return CaptureFirst;
+            case 2: return Evaluation;
+            default: throw new Error("Invalid value for enum PlayoutPolicy: " + x);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class PromotionRequiredException extends WarpSquareException {
+    public PromotionRequiredException(String message) {
+        super(message);
+    }
+}
@@ -0,0 +1,53 @@
+// Automatically generated by flapigen
+package club.gamza.warpsquare.engine;
+
+
+public final class TablebaseEntry {
+
+    private TablebaseEntry() {}
+
+    public final Wdl getWdl() {
+        int ret = do_getWdl(mNativeObj);
+        Wdl convRet = Wdl.fromInt(ret);
+
+        return convRet;
+    }
+    private static native int do_getWdl(long self);
+
+    public final long getDistance() {
+        long ret = do_getDistance(mNativeObj);
+
+        return ret;
+    }
+    private static native long do_getDistance(long self);
+
+    public synchronized void delete() {
+        if (mNativeObj != 0) {
+            do_delete(mNativeObj);
+            mNativeObj = 0;
+       }
+    }
+    @Override
+    protected void finalize() throws Throwable {
+        try {
+            delete();
+        }
+        finally {
+             super.finalize();
+        }
+    }
+    private static native void do_delete(long me);
+    /*package*/ TablebaseEntry(InternalPointerMarker marker, long ptr) {
+        assert marker == InternalPointerMarker.RAW_PTR;
+        this.mNativeObj = ptr;
+    }
+    /*package*/ long mNativeObj;
+
+    static {
+        try {
+            NativeUtils.loadLibraryFromJar();
+        } catch (java.io.IOException e) {
+            e.printStackTrace();
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class UnsupportedTablebaseException extends WarpSquareException {
+    public UnsupportedTablebaseException(String message) {
+        super(message);
+    }
+}
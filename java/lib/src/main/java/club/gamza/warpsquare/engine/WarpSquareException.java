@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class WarpSquareException extends Exception {
+    public WarpSquareException(String message) {
+        super(message);
+    }
+}
@@ -0,0 +1,23 @@
+// Automatically generated by flapigen
+package club.gamza.warpsquare.engine;
+
+
+public enum Wdl {
+    Win(0),
+    Draw(1),
+    Loss(2);
+
+    private final int value;
+    Wdl(int value) {
+        this.value = value;
+    }
+    public final int getValue() { return value; }
+    /*package*/ static Wdl fromInt(int x) {
+        switch (x) {
+            case 0: return Win;
+            case 1: return Draw;
+            case 2: return Loss;
+            default: throw new Error("Invalid value for enum Wdl: " + x);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+package club.gamza.warpsquare.engine;
+
+public class WrongSideToMoveException extends WarpSquareException {
+    public WrongSideToMoveException(String message) {
+        super(message);
+    }
+}
@@ -1,12 +1,109 @@
+use std::sync::Arc;
+
 use crate::jni_c_header::*;
 use ::warp_square_engine::{
     bit_board::BitBoard,
+    error::WarpSquareError,
+    eval::Evaluation,
     game::Game,
+    mcts::{Mcts, MctsConfig, PlayoutPolicy},
+    opening_book::OpeningBook,
     piece::{Piece, PieceType},
-    piece_move::PieceMove,
+    piece_move::{MoveType, PieceMove},
+    search::{search_parallel, SearchLimits},
     square::{Color, File, Level, Rank, Square},
+    tablebase::{Tablebase, TablebaseEntry, Wdl},
 };
 
+/// WarpSquareError 종류마다 다른 Java 예외를 던집니다.
+fn jni_throw_warp_square_error(env: *mut JNIEnv, error: &WarpSquareError) {
+    let exception_class = match error {
+        WarpSquareError::NoPieceAtSource => swig_jni_find_class!(
+            NO_PIECE_AT_SOURCE_EXCEPTION,
+            "club/gamza/warpsquare/engine/NoPieceAtSourceException"
+        ),
+        WarpSquareError::WrongSideToMove => swig_jni_find_class!(
+            WRONG_SIDE_TO_MOVE_EXCEPTION,
+            "club/gamza/warpsquare/engine/WrongSideToMoveException"
+        ),
+        WarpSquareError::IllegalDestination => swig_jni_find_class!(
+            ILLEGAL_DESTINATION_EXCEPTION,
+            "club/gamza/warpsquare/engine/IllegalDestinationException"
+        ),
+        WarpSquareError::KingInCheck => swig_jni_find_class!(
+            KING_IN_CHECK_EXCEPTION,
+            "club/gamza/warpsquare/engine/KingInCheckException"
+        ),
+        WarpSquareError::InvalidSquareForLevel => swig_jni_find_class!(
+            INVALID_SQUARE_FOR_LEVEL_EXCEPTION,
+            "club/gamza/warpsquare/engine/InvalidSquareForLevelException"
+        ),
+        WarpSquareError::PromotionRequired => swig_jni_find_class!(
+            PROMOTION_REQUIRED_EXCEPTION,
+            "club/gamza/warpsquare/engine/PromotionRequiredException"
+        ),
+        WarpSquareError::InvalidPromotion => swig_jni_find_class!(
+            INVALID_PROMOTION_EXCEPTION,
+            "club/gamza/warpsquare/engine/InvalidPromotionException"
+        ),
+        WarpSquareError::NoAttackBoard => swig_jni_find_class!(
+            NO_ATTACK_BOARD_EXCEPTION,
+            "club/gamza/warpsquare/engine/NoAttackBoardException"
+        ),
+        WarpSquareError::PinOccupied => swig_jni_find_class!(
+            PIN_OCCUPIED_EXCEPTION,
+            "club/gamza/warpsquare/engine/PinOccupiedException"
+        ),
+        WarpSquareError::PinNotAdjacent => swig_jni_find_class!(
+            PIN_NOT_ADJACENT_EXCEPTION,
+            "club/gamza/warpsquare/engine/PinNotAdjacentException"
+        ),
+        WarpSquareError::BoardOverloaded => swig_jni_find_class!(
+            BOARD_OVERLOADED_EXCEPTION,
+            "club/gamza/warpsquare/engine/BoardOverloadedException"
+        ),
+        WarpSquareError::BoardMoveBackward => swig_jni_find_class!(
+            BOARD_MOVE_BACKWARD_EXCEPTION,
+            "club/gamza/warpsquare/engine/BoardMoveBackwardException"
+        ),
+        WarpSquareError::GameOver => swig_jni_find_class!(
+            GAME_OVER_EXCEPTION,
+            "club/gamza/warpsquare/engine/GameOverException"
+        ),
+        WarpSquareError::NothingToUndo => swig_jni_find_class!(
+            NOTHING_TO_UNDO_EXCEPTION,
+            "club/gamza/warpsquare/engine/NothingToUndoException"
+        ),
+        WarpSquareError::InvalidNotation(_) => swig_jni_find_class!(
+            INVALID_NOTATION_EXCEPTION,
+            "club/gamza/warpsquare/engine/InvalidNotationException"
+        ),
+        WarpSquareError::UnsupportedTablebase => swig_jni_find_class!(
+            UNSUPPORTED_TABLEBASE_EXCEPTION,
+            "club/gamza/warpsquare/engine/UnsupportedTablebaseException"
+        ),
+    };
+
+    jni_throw(env, exception_class, &error.to_string());
+}
+
+foreign_typemap!(
+    ($p:r_type) <T> Result<T, WarpSquareError> => swig_i_type!(T) {
+        $out = match $p {
+            Ok(x) => {
+                swig_from_rust_to_i_type!(T, x, ret)
+                ret
+            }
+            Err(error) => {
+                jni_throw_warp_square_error(env, &error);
+                return <swig_i_type!(T)>::jni_invalid_value();
+            }
+        };
+    };
+    ($p:f_type, unique_prefix="/*Result<swig_subst_type!(T), WarpSquareError>*/") => "/*Result<swig_subst_type!(T), WarpSquareError>*/swig_f_type!(T)"
+        "swig_foreign_from_i_type!(T, $p)";
+);
+
 foreign_enum!(
     enum PieceType {
         Pawn = PieceType::Pawn,
@@ -71,6 +168,13 @@ foreign_enum!(
     }
 );
 
+foreign_enum!(
+    enum MoveType {
+        Move = MoveType::Move,
+        BoardMove = MoveType::BoardMove,
+    }
+);
+
 foreign_class!(class BitBoard {
     self_type BitBoard;
     private constructor = empty;
@@ -127,6 +231,13 @@ foreign_class!(class PieceMove {
     fn PieceMove::getDestination(&self) -> Square {
         this.destination.clone()
     }
+    fn PieceMove::getPromotion(&self) -> Option<PieceType> {
+        this.promotion
+    }
+    fn PieceMove::getMoveType(&self) -> MoveType {
+        this.move_type
+    }
+    fn PieceMove::new_board_move(source: Level, destination: Level) -> PieceMove; alias newBoardMove;
     foreign_code r#"
     static {
         try {
@@ -161,23 +272,179 @@ foreign_class!(class Square {
 "#;
 });
 
+foreign_class!(class Evaluation {
+    self_type Evaluation;
+    private constructor = empty;
+    fn Evaluation::getMaterial(&self) -> i32 {
+        this.material
+    }
+    fn Evaluation::getNeutralControl(&self) -> i32 {
+        this.neutral_control
+    }
+    fn Evaluation::getMobility(&self) -> i32 {
+        this.mobility
+    }
+    fn Evaluation::getAttackBoardSafety(&self) -> i32 {
+        this.attack_board_safety
+    }
+    fn Evaluation::getKingExposure(&self) -> i32 {
+        this.king_exposure
+    }
+    fn Evaluation::getPawnStructure(&self) -> i32 {
+        this.pawn_structure
+    }
+    fn Evaluation::get_total(&self) -> i32; alias getTotal;
+    foreign_code r#"
+    static {
+        try {
+            NativeUtils.loadLibraryFromJar();
+        } catch (java.io.IOException e) {
+            e.printStackTrace();
+        }
+    }
+"#;
+});
+
+foreign_class!(class OpeningBook {
+    self_type OpeningBook;
+    private constructor = empty;
+    fn OpeningBook::load(path: &str) -> Result<OpeningBook, String> {
+        OpeningBook::load(path).map_err(|error| error.to_string())
+    }
+    fn OpeningBook::get_size(&self) -> usize; alias getSize;
+    foreign_code r#"
+    static {
+        try {
+            NativeUtils.loadLibraryFromJar();
+        } catch (java.io.IOException e) {
+            e.printStackTrace();
+        }
+    }
+"#;
+});
+
+foreign_enum!(
+    enum PlayoutPolicy {
+        Random = PlayoutPolicy::Random,
+        CaptureFirst = PlayoutPolicy::CaptureFirst,
+        Evaluation = PlayoutPolicy::Evaluation,
+    }
+);
+
+foreign_class!(class Mcts {
+    self_type Mcts;
+    constructor Mcts::create(
+        iterations: u32,
+        time_millis: u32,
+        exploration: f64,
+        policy: PlayoutPolicy,
+        max_playout_ply: u32,
+    ) -> Mcts {
+        let time = match time_millis {
+            0 => None,
+            _ => Some(std::time::Duration::from_millis(time_millis as u64)),
+        };
+
+        Mcts::new(MctsConfig {
+            policy,
+            exploration,
+            iterations,
+            time,
+            max_playout_ply,
+            ..MctsConfig::new()
+        })
+    }
+    fn Mcts::search(&mut self, game: &mut Game) -> Option<PieceMove>; alias chooseMove;
+    fn Mcts::clear(&mut self);
+    foreign_code r#"
+    static {
+        try {
+            NativeUtils.loadLibraryFromJar();
+        } catch (java.io.IOException e) {
+            e.printStackTrace();
+        }
+    }
+"#;
+});
+
+foreign_enum!(
+    enum Wdl {
+        Win = Wdl::Win,
+        Draw = Wdl::Draw,
+        Loss = Wdl::Loss,
+    }
+);
+
+foreign_class!(class TablebaseEntry {
+    self_type TablebaseEntry;
+    private constructor = empty;
+    fn TablebaseEntry::getWdl(&self) -> Wdl {
+        this.wdl
+    }
+    fn TablebaseEntry::getDistance(&self) -> u32 {
+        this.distance
+    }
+    foreign_code r#"
+    static {
+        try {
+            NativeUtils.loadLibraryFromJar();
+        } catch (java.io.IOException e) {
+            e.printStackTrace();
+        }
+    }
+"#;
+});
+
 foreign_class!(class Game {
     self_type Game;
     constructor Game::new() -> Game;
     fn Game::get_attack_squares(&self, square: &Square) -> Vec<Square>; alias getAttackSquares;
     fn Game::legal_move(&self, _: PieceMove) -> bool; alias legalMove;
-    fn Game::push_move(&mut self, _: PieceMove) -> Result<(), &'static str>; alias pushMove;
-    fn Game::pop_move(&mut self) -> Result<PieceMove, &'static str>; alias popMove;
+    fn Game::legal_moves(&self) -> Vec<PieceMove>; alias legalMoves;
+    fn Game::is_check(&self) -> bool; alias isCheck;
+    fn Game::is_checkmate(&self) -> bool; alias isCheckmate;
+    fn Game::is_stalemate(&self) -> bool; alias isStalemate;
+    fn Game::is_draw(&self) -> bool; alias isDraw;
+    fn Game::can_claim_draw(&self) -> bool; alias canClaimDraw;
+    fn Game::is_game_over(&self) -> bool; alias isGameOver;
+    fn Game::get_winner(&self) -> Option<Color>; alias getWinner;
+    fn Game::push_move(&mut self, _: PieceMove) -> Result<(), WarpSquareError>; alias pushMove;
+    fn Game::pop_move(&mut self) -> Result<PieceMove, WarpSquareError>; alias popMove;
     fn Game::print(&self);
     fn Game::getTurn(&self) -> Color {
         this.turn
     }
+    fn Game::getHalfmoveClock(&self) -> u32 {
+        this.halfmove_clock
+    }
+    fn Game::getFullmoveNumber(&self) -> u32 {
+        this.fullmove_number
+    }
     fn Game::getPieces(&self) -> Vec<Piece> {
-        this.board.pieces.clone()
+        this.board.pieces().copied().collect()
     }
     fn Game::getCapturedPieces(&self) -> Vec<Piece> {
         this.board.captured_pieces.clone()
     }
+    fn Game::evaluate(&self) -> Evaluation;
+    fn Game::loadTablebase(&mut self, path: &str) -> Result<(), String> {
+        let tablebase = Tablebase::load(path).map_err(|error| error.to_string())?;
+        this.tablebase = Some(Arc::new(tablebase));
+
+        Ok(())
+    }
+    fn Game::probe_tablebase(&self) -> Option<TablebaseEntry>; alias probeTablebase;
+    fn Game::getBookMove(&self, book: &OpeningBook, random: i64) -> Option<PieceMove> {
+        this.get_book_move(book, random as u64)
+    }
+    fn Game::findBestMove(&self, depth: u32, time_millis: u32, threads: u32) -> Option<PieceMove> {
+        let time = match time_millis {
+            0 => None,
+            _ => Some(std::time::Duration::from_millis(time_millis as u64)),
+        };
+
+        search_parallel(this, SearchLimits::new(depth, time), threads as usize).best_move
+    }
     foreign_code r#"
     static {
         try {
@@ -1,15 +1,319 @@
-use pyo3::prelude::*;
-use ::warp_square_engine::get_hello;
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
-/// Formats the sum of two numbers as string.
-#[pyfunction]
-fn say_hello(nickname: String) -> PyResult<String> {
-    Ok(get_hello(nickname))
+use ::warp_square_engine::{
+    error, game,
+    mcts::{self, MctsConfig, PlayoutPolicy},
+    opening_book,
+    piece_move::PieceMove,
+    search::{search_parallel, SearchLimits},
+    tablebase::{self, Wdl},
+};
+use pyo3::{
+    create_exception,
+    exceptions::{PyException, PyIOError, PyValueError},
+    prelude::*,
+};
+
+create_exception!(warp_square_engine, WarpSquareError, PyException);
+create_exception!(warp_square_engine, NoPieceAtSourceError, WarpSquareError);
+create_exception!(warp_square_engine, WrongSideToMoveError, WarpSquareError);
+create_exception!(warp_square_engine, IllegalDestinationError, WarpSquareError);
+create_exception!(warp_square_engine, KingInCheckError, WarpSquareError);
+create_exception!(
+    warp_square_engine,
+    InvalidSquareForLevelError,
+    WarpSquareError
+);
+create_exception!(warp_square_engine, PromotionRequiredError, WarpSquareError);
+create_exception!(warp_square_engine, InvalidPromotionError, WarpSquareError);
+create_exception!(warp_square_engine, NoAttackBoardError, WarpSquareError);
+create_exception!(warp_square_engine, PinOccupiedError, WarpSquareError);
+create_exception!(warp_square_engine, PinNotAdjacentError, WarpSquareError);
+create_exception!(warp_square_engine, BoardOverloadedError, WarpSquareError);
+create_exception!(warp_square_engine, BoardMoveBackwardError, WarpSquareError);
+create_exception!(warp_square_engine, GameOverError, WarpSquareError);
+create_exception!(warp_square_engine, NothingToUndoError, WarpSquareError);
+create_exception!(warp_square_engine, InvalidNotationError, WarpSquareError);
+create_exception!(
+    warp_square_engine,
+    UnsupportedTablebaseError,
+    WarpSquareError
+);
+
+/// WarpSquareError 종류마다 다른 Python 예외로 변환합니다.
+fn into_py_err(error: error::WarpSquareError) -> PyErr {
+    use error::WarpSquareError as E;
+
+    let message = error.to_string();
+
+    match error {
+        E::NoPieceAtSource => NoPieceAtSourceError::new_err(message),
+        E::WrongSideToMove => WrongSideToMoveError::new_err(message),
+        E::IllegalDestination => IllegalDestinationError::new_err(message),
+        E::KingInCheck => KingInCheckError::new_err(message),
+        E::InvalidSquareForLevel => InvalidSquareForLevelError::new_err(message),
+        E::PromotionRequired => PromotionRequiredError::new_err(message),
+        E::InvalidPromotion => InvalidPromotionError::new_err(message),
+        E::NoAttackBoard => NoAttackBoardError::new_err(message),
+        E::PinOccupied => PinOccupiedError::new_err(message),
+        E::PinNotAdjacent => PinNotAdjacentError::new_err(message),
+        E::BoardOverloaded => BoardOverloadedError::new_err(message),
+        E::BoardMoveBackward => BoardMoveBackwardError::new_err(message),
+        E::GameOver => GameOverError::new_err(message),
+        E::NothingToUndo => NothingToUndoError::new_err(message),
+        E::InvalidNotation(_) => InvalidNotationError::new_err(message),
+        E::UnsupportedTablebase => UnsupportedTablebaseError::new_err(message),
+    }
+}
+
+#[pyclass]
+struct OpeningBook {
+    book: opening_book::OpeningBook,
+}
+
+#[pymethods]
+impl OpeningBook {
+    #[staticmethod]
+    fn load(path: &str) -> PyResult<Self> {
+        match opening_book::OpeningBook::load(path) {
+            Ok(book) => Ok(Self { book }),
+            Err(error) => Err(PyIOError::new_err(error.to_string())),
+        }
+    }
+
+    fn __len__(&self) -> usize {
+        self.book.get_size()
+    }
+}
+
+#[pyclass]
+struct Tablebase {
+    tablebase: Arc<tablebase::Tablebase>,
+}
+
+#[pymethods]
+impl Tablebase {
+    #[staticmethod]
+    fn load(path: &str) -> PyResult<Self> {
+        match tablebase::Tablebase::load(path) {
+            Ok(tablebase) => Ok(Self {
+                tablebase: Arc::new(tablebase),
+            }),
+            Err(error) => Err(PyIOError::new_err(error.to_string())),
+        }
+    }
+}
+
+/// policy는 "random", "capture_first", "evaluation" 중 하나입니다.
+#[pyclass]
+struct MctsPlayer {
+    mcts: mcts::Mcts,
+}
+
+#[pymethods]
+impl MctsPlayer {
+    #[new]
+    #[pyo3(signature = (iterations=1000, time_ms=None, exploration=std::f64::consts::SQRT_2, policy="capture_first", max_playout_ply=40))]
+    fn new(
+        iterations: u32,
+        time_ms: Option<u64>,
+        exploration: f64,
+        policy: &str,
+        max_playout_ply: u32,
+    ) -> PyResult<Self> {
+        let policy = match policy {
+            "random" => PlayoutPolicy::Random,
+            "capture_first" => PlayoutPolicy::CaptureFirst,
+            "evaluation" => PlayoutPolicy::Evaluation,
+            _ => return Err(PyValueError::new_err(format!("Unknown policy: {}", policy))),
+        };
+
+        Ok(Self {
+            mcts: mcts::Mcts::new(MctsConfig {
+                policy,
+                exploration,
+                iterations,
+                time: time_ms.map(Duration::from_millis),
+                max_playout_ply,
+                ..MctsConfig::new()
+            }),
+        })
+    }
+
+    fn choose_move(&mut self, mut game: PyRefMut<Game>) -> Option<String> {
+        self.mcts
+            .search(&mut game.game)
+            .map(|piece_move| piece_move.to_string())
+    }
+
+    fn clear(&mut self) {
+        self.mcts.clear();
+    }
+}
+
+/// 수는 a2W-a4N, QL1-QL3 같은 표기 문자열로 주고받습니다.
+#[pyclass]
+struct Game {
+    game: game::Game,
+}
+
+#[pymethods]
+impl Game {
+    #[new]
+    fn new() -> Self {
+        Self {
+            game: game::Game::new(),
+        }
+    }
+
+    fn push_move(&mut self, notation: &str) -> PyResult<()> {
+        let piece_move = notation.parse::<PieceMove>().map_err(into_py_err)?;
+
+        self.game.push_move(piece_move).map_err(into_py_err)
+    }
+
+    fn pop_move(&mut self) -> PyResult<String> {
+        match self.game.pop_move() {
+            Ok(piece_move) => Ok(piece_move.to_string()),
+            Err(error) => Err(into_py_err(error)),
+        }
+    }
+
+    fn legal_moves(&self) -> Vec<String> {
+        self.game
+            .legal_moves()
+            .iter()
+            .map(|piece_move| piece_move.to_string())
+            .collect()
+    }
+
+    fn is_check(&self) -> bool {
+        self.game.is_check()
+    }
+
+    fn is_checkmate(&self) -> bool {
+        self.game.is_checkmate()
+    }
+
+    fn is_stalemate(&self) -> bool {
+        self.game.is_stalemate()
+    }
+
+    fn is_draw(&self) -> bool {
+        self.game.is_draw()
+    }
+
+    fn can_claim_draw(&self) -> bool {
+        self.game.can_claim_draw()
+    }
+
+    fn is_game_over(&self) -> bool {
+        self.game.is_game_over()
+    }
+
+    /// 항목 이름별 평가 점수, 백의 입장에서 본 점수입니다.
+    fn evaluate(&self) -> HashMap<&'static str, i32> {
+        let evaluation = self.game.evaluate();
+
+        HashMap::from([
+            ("material", evaluation.material),
+            ("neutral_control", evaluation.neutral_control),
+            ("mobility", evaluation.mobility),
+            ("attack_board_safety", evaluation.attack_board_safety),
+            ("king_exposure", evaluation.king_exposure),
+            ("pawn_structure", evaluation.pawn_structure),
+            ("total", evaluation.get_total()),
+        ])
+    }
+
+    /// random이 같으면 같은 수를 고릅니다. (예: random.getrandbits(64))
+    fn book_move(&self, book: &OpeningBook, random: u64) -> Option<String> {
+        self.game
+            .get_book_move(&book.book, random)
+            .map(|piece_move| piece_move.to_string())
+    }
+
+    fn set_tablebase(&mut self, tablebase: &Tablebase) {
+        self.game.tablebase = Some(tablebase.tablebase.clone());
+    }
+
+    /// ("win" | "draw" | "loss", 메이트까지 남은 반수)
+    fn probe_tablebase(&self) -> Option<(&'static str, u32)> {
+        self.game.probe_tablebase().map(|entry| {
+            let wdl = match entry.wdl {
+                Wdl::Win => "win",
+                Wdl::Draw => "draw",
+                Wdl::Loss => "loss",
+            };
+
+            (wdl, entry.distance)
+        })
+    }
+
+    #[pyo3(signature = (depth, time_ms=None, threads=1))]
+    fn best_move(&self, depth: u32, time_ms: Option<u64>, threads: usize) -> Option<String> {
+        let limits = SearchLimits::new(depth, time_ms.map(Duration::from_millis));
+
+        search_parallel(&self.game, limits, threads)
+            .best_move
+            .map(|piece_move| piece_move.to_string())
+    }
 }
 
-/// A Python module implemented in Rust.
 #[pymodule]
-fn warp_square_engine(_py: Python, m: &PyModule) -> PyResult<()> {
-    m.add_function(wrap_pyfunction!(say_hello, m)?)?;
+fn warp_square_engine(py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<Game>()?;
+    m.add_class::<OpeningBook>()?;
+    m.add_class::<Tablebase>()?;
+    m.add_class::<MctsPlayer>()?;
+
+    m.add("WarpSquareError", py.get_type::<WarpSquareError>())?;
+    m.add(
+        "NoPieceAtSourceError",
+        py.get_type::<NoPieceAtSourceError>(),
+    )?;
+    m.add(
+        "WrongSideToMoveError",
+        py.get_type::<WrongSideToMoveError>(),
+    )?;
+    m.add(
+        "IllegalDestinationError",
+        py.get_type::<IllegalDestinationError>(),
+    )?;
+    m.add("KingInCheckError", py.get_type::<KingInCheckError>())?;
+    m.add(
+        "InvalidSquareForLevelError",
+        py.get_type::<InvalidSquareForLevelError>(),
+    )?;
+    m.add(
+        "PromotionRequiredError",
+        py.get_type::<PromotionRequiredError>(),
+    )?;
+    m.add(
+        "InvalidPromotionError",
+        py.get_type::<InvalidPromotionError>(),
+    )?;
+    m.add("NoAttackBoardError", py.get_type::<NoAttackBoardError>())?;
+    m.add("PinOccupiedError", py.get_type::<PinOccupiedError>())?;
+    m.add("PinNotAdjacentError", py.get_type::<PinNotAdjacentError>())?;
+    m.add(
+        "BoardOverloadedError",
+        py.get_type::<BoardOverloadedError>(),
+    )?;
+    m.add(
+        "BoardMoveBackwardError",
+        py.get_type::<BoardMoveBackwardError>(),
+    )?;
+    m.add("GameOverError", py.get_type::<GameOverError>())?;
+    m.add("NothingToUndoError", py.get_type::<NothingToUndoError>())?;
+    m.add(
+        "InvalidNotationError",
+        py.get_type::<InvalidNotationError>(),
+    )?;
+    m.add(
+        "UnsupportedTablebaseError",
+        py.get_type::<UnsupportedTablebaseError>(),
+    )?;
+
     Ok(())
 }
@@ -0,0 +1,171 @@
+use crate::{
+    bit_board::BitBoard,
+    square::{Color, NUM_FILES, NUM_RANKS, NUM_SQUARES},
+};
+
+/// 각 Square에서 (랭크, 파일) 차이만큼 떨어진 Square 표, 6×10 격자를 벗어나면 제외합니다.
+const fn leaper_table(offsets: &[(i8, i8)]) -> [BitBoard; NUM_SQUARES] {
+    let mut table = [BitBoard::EMPTY; NUM_SQUARES];
+    let mut square = 0;
+
+    while square < NUM_SQUARES {
+        let rank = (square % NUM_RANKS as usize) as i8;
+        let file = (square / NUM_RANKS as usize) as i8;
+
+        let mut bits = 0;
+        let mut i = 0;
+
+        while i < offsets.len() {
+            let (rank_offset, file_offset) = offsets[i];
+            let destination_rank = rank + rank_offset;
+            let destination_file = file + file_offset;
+
+            if destination_rank >= 0
+                && destination_rank < NUM_RANKS as i8
+                && destination_file >= 0
+                && destination_file < NUM_FILES as i8
+            {
+                bits |= 1
+                    << (destination_rank as usize + destination_file as usize * NUM_RANKS as usize);
+            }
+
+            i += 1;
+        }
+
+        table[square] = BitBoard::from_bits_retain(bits);
+        square += 1;
+    }
+
+    table
+}
+
+/// 각 Square에서 (랭크, 파일) 방향으로 격자 끝까지 이어지는 Square 표
+const fn ray_table(rank_offset: i8, file_offset: i8) -> [BitBoard; NUM_SQUARES] {
+    let mut table = [BitBoard::EMPTY; NUM_SQUARES];
+    let mut square = 0;
+
+    while square < NUM_SQUARES {
+        let mut rank = (square % NUM_RANKS as usize) as i8 + rank_offset;
+        let mut file = (square / NUM_RANKS as usize) as i8 + file_offset;
+
+        let mut bits = 0;
+
+        while rank >= 0 && rank < NUM_RANKS as i8 && file >= 0 && file < NUM_FILES as i8 {
+            bits |= 1 << (rank as usize + file as usize * NUM_RANKS as usize);
+
+            rank += rank_offset;
+            file += file_offset;
+        }
+
+        table[square] = BitBoard::from_bits_retain(bits);
+        square += 1;
+    }
+
+    table
+}
+
+/// Square 번호가 커지는 방향의 광선, 가장 가까운 Square가 가장 낮은 비트입니다.
+static POSITIVE_RAYS: [[BitBoard; NUM_SQUARES]; 4] = [
+    ray_table(1, 0),
+    ray_table(0, 1),
+    ray_table(1, 1),
+    ray_table(-1, 1),
+];
+
+/// Square 번호가 작아지는 방향의 광선, 가장 가까운 Square가 가장 높은 비트입니다.
+static NEGATIVE_RAYS: [[BitBoard; NUM_SQUARES]; 4] = [
+    ray_table(-1, 0),
+    ray_table(0, -1),
+    ray_table(-1, -1),
+    ray_table(1, -1),
+];
+
+const ORTHOGONAL: [usize; 2] = [0, 1];
+const DIAGONAL: [usize; 2] = [2, 3];
+
+/// 처음 막힌 Square까지 포함하고 그 뒤는 제외한 광선
+fn get_ray_attacks(square: usize, occupied: BitBoard, directions: [usize; 2]) -> BitBoard {
+    let mut attacks = BitBoard::EMPTY;
+
+    for direction in directions {
+        let ray = POSITIVE_RAYS[direction][square];
+        let blockers = (ray & occupied).bits();
+
+        attacks |= match blockers {
+            0 => ray,
+            _ => ray ^ POSITIVE_RAYS[direction][blockers.trailing_zeros() as usize],
+        };
+
+        let ray = NEGATIVE_RAYS[direction][square];
+        let blockers = (ray & occupied).bits();
+
+        attacks |= match blockers {
+            0 => ray,
+            _ => ray ^ NEGATIVE_RAYS[direction][63 - blockers.leading_zeros() as usize],
+        };
+    }
+
+    attacks
+}
+
+/// occupied의 Square에서 막히는 룩 행마, Level 영역은 무시합니다.
+pub fn get_rook_attacks(square: BitBoard, occupied: BitBoard) -> BitBoard {
+    get_ray_attacks(square.get_index(), occupied.remove_level(), ORTHOGONAL)
+}
+
+pub fn get_bishop_attacks(square: BitBoard, occupied: BitBoard) -> BitBoard {
+    get_ray_attacks(square.get_index(), occupied.remove_level(), DIAGONAL)
+}
+
+pub fn get_queen_attacks(square: BitBoard, occupied: BitBoard) -> BitBoard {
+    get_rook_attacks(square, occupied) | get_bishop_attacks(square, occupied)
+}
+
+pub static KNIGHT_ATTACKS: [BitBoard; NUM_SQUARES] = leaper_table(&[
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+]);
+
+pub static KING_ATTACKS: [BitBoard; NUM_SQUARES] = leaper_table(&[
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+]);
+
+/// 색별로 폰이 한 칸 전진하는 Square
+pub static PAWN_PUSHES: [[BitBoard; NUM_SQUARES]; 2] =
+    [leaper_table(&[(1, 0)]), leaper_table(&[(-1, 0)])];
+
+/// 색별로 폰이 공격하는 대각선 앞 Square
+pub static PAWN_ATTACKS: [[BitBoard; NUM_SQUARES]; 2] = [
+    leaper_table(&[(1, -1), (1, 1)]),
+    leaper_table(&[(-1, -1), (-1, 1)]),
+];
+
+/// Level 영역을 무시하고 Square 하나의 나이트 행마를 반환합니다.
+pub fn get_knight_attacks(square: BitBoard) -> BitBoard {
+    KNIGHT_ATTACKS[square.get_index()]
+}
+
+pub fn get_king_attacks(square: BitBoard) -> BitBoard {
+    KING_ATTACKS[square.get_index()]
+}
+
+pub fn get_pawn_pushes(square: BitBoard, color: Color) -> BitBoard {
+    PAWN_PUSHES[color as usize][square.get_index()]
+}
+
+pub fn get_pawn_attacks(square: BitBoard, color: Color) -> BitBoard {
+    PAWN_ATTACKS[color as usize][square.get_index()]
+}
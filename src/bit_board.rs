@@ -2,7 +2,7 @@ use std::ops::{BitAnd, BitOr, Index, IndexMut};
 
 use bitflags::bitflags;
 
-use crate::square::{Color, File, Level, Rank, Square, NUM_RANKS};
+use crate::square::{Color, File, Level, Rank, Square, NUM_FILES, NUM_RANKS};
 
 bitflags! {
     #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug, Hash)]
@@ -180,6 +180,22 @@ impl BitBoard {
     pub const KL5: BitBoard = BitBoard::from_bits_retain(0b1110 << Self::LEVEL_SHIFT);
     pub const KL6: BitBoard = BitBoard::from_bits_retain(0b1111 << Self::LEVEL_SHIFT);
 
+    /// 랭크와 파일 번호의 합이 홀수인 밝은 칸, 모든 Level에서 색이 같습니다.
+    pub const LIGHT_SQUARES: BitBoard = {
+        let mut bits = 0;
+        let mut shift = 0;
+
+        while shift < NUM_RANKS * NUM_FILES {
+            if (shift % NUM_RANKS + shift / NUM_RANKS) % 2 == 1 {
+                bits |= 1 << shift;
+            }
+
+            shift += 1;
+        }
+
+        BitBoard::from_bits_retain(bits)
+    };
+
     pub fn down(&self) -> Self {
         Self::from_bits_retain((*self & !Self::ZERO_RANKS).bits() >> 1)
     }
@@ -204,7 +220,7 @@ impl BitBoard {
         Self::from_bits_retain(((*self & !Self::ZERO_RANKS).bits() >> 1) << NUM_RANKS)
     }
 
-        pub fn up_left(&self) -> Self {
+    pub fn up_left(&self) -> Self {
         Self::from_bits_retain(((*self & !Self::NINE_RANKS).bits() << 1) >> NUM_RANKS)
     }
 
@@ -259,7 +275,7 @@ impl BitBoard {
 
             current = next;
         }
-        
+
         result
     }
 
@@ -275,6 +291,11 @@ impl BitBoard {
         File::from_u8(shift / NUM_RANKS)
     }
 
+    /// 보드 안에서의 Square 번호 (0 ~ 59), Square가 하나만 있어야 합니다.
+    pub fn get_index(&self) -> usize {
+        self.remove_level().bits().trailing_zeros() as usize
+    }
+
     pub fn get_level(&self) -> Level {
         Level::from_u8((self.bits() >> Self::LEVEL_SHIFT) as u8)
     }
@@ -334,6 +355,15 @@ impl BoardType {
         .iter()
         .copied()
     }
+
+    /// 공격 보드의 원래 주인, 메인 보드는 None
+    pub fn get_owner(&self) -> Option<Color> {
+        match self {
+            Self::WhiteQueen | Self::WhiteKing => Some(Color::White),
+            Self::BlackQueen | Self::BlackKing => Some(Color::Black),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Eq, PartialEq, PartialOrd, Debug, Hash)]
@@ -361,6 +391,12 @@ impl BitBoardSet {
     }
 }
 
+impl Default for BitBoardSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Index<BoardType> for BitBoardSet {
     type Output = BitBoard;
 
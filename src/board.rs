@@ -1,47 +1,81 @@
 use crate::{
+    attack_table,
     bit_board::{BitBoard, BitBoardSet, BoardType},
+    castling::CastlingRights,
     color_mask::ColorMask,
-    piece::{Piece, PieceType},
-    square::{Color, Level},
+    error::WarpSquareError,
+    piece::{Piece, PieceType, NUM_PIECES},
+    piece_move::{MoveType, PieceMove},
+    square::{Color, File, Level, Rank, Square, NUM_SQUARES},
+    zobrist,
 };
 
+/// 보드 전체를 복사해 두는 체크포인트
+///
+/// 수를 하나씩 되돌릴 때는 make_move가 반환하는 MoveUndo를 사용합니다.
+#[derive(Clone)]
 pub struct BoardSnapshot {
-    pieces: Vec<Piece>,
-    captured_pieces: Vec<Piece>,
-    board_set: [(BoardType, Level); 7],
-    occupied: ColorMask,
+    board: Board,
 }
 
 impl BoardSnapshot {
     pub fn new(board: &Board) -> Self {
         Self {
-            pieces: board.pieces.clone(),
-            captured_pieces: board.captured_pieces.clone(),
-            board_set: board.board_set.clone(),
-            occupied: board.occupied_piece.clone(),
+            board: board.clone(),
         }
     }
 
     pub fn restore(&self, board: &mut Board) {
-        board.pieces = self.pieces.clone();
-        board.captured_pieces = self.captured_pieces.clone();
-        board.board_set = self.board_set.clone();
-        board.occupied_piece = self.occupied.clone();
+        *board = self.board.clone();
     }
 }
 
+/// 직전 수에서 두 칸 전진한 폰의 정보
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct EnPassant {
+    /// 폰이 건너뛴 Square, Level 영역은 비어 있습니다.
+    pub square: BitBoard,
+    /// 앙파상으로 잡힐 폰의 위치
+    pub pawn: BitBoard,
+}
+
+/// make_move로 바뀐 부분만 담은 되돌리기 기록
+#[derive(Clone, Debug)]
+pub struct MoveUndo {
+    /// 움직이기 전의 기물, 공격 보드 이동이면 None
+    pub piece: Option<Piece>,
+    /// 캐슬링으로 킹과 자리를 바꾼 룩
+    pub rook: Option<Piece>,
+    /// 기물을 잡았다면 captured_pieces의 마지막 기물이 잡힌 기물
+    pub is_capture: bool,
+    pub en_passant: Option<EnPassant>,
+    pub castling_rights: CastlingRights,
+    pub hash: u64,
+}
+
+#[derive(Clone)]
 pub struct Board {
-    pub pieces: Vec<Piece>,
+    /// 보드, Square 번호별로 놓인 기물
+    mailbox: [[Option<Piece>; NUM_SQUARES]; 7],
+    /// 색, 기물 종류별로 기물이 있는 Square, Level 영역은 비어 있습니다.
+    pub piece_boards: [[BitBoardSet; NUM_PIECES]; 2],
     pub captured_pieces: Vec<Piece>,
     pub board_set: [(BoardType, Level); 7],
+    pub en_passant: Option<EnPassant>,
+    pub castling_rights: CastlingRights,
     pub occupied_void: BitBoardSet,
     pub occupied_piece: ColorMask,
+    /// 미끄러지는 기물의 광선을 막는 Square, Level 영역은 비어 있습니다.
+    pub ray_occupied: BitBoard,
+    /// 기물 배치, 보드 위치, 캐슬링 권리, 앙파상의 Zobrist 해시
+    pub hash: u64,
 }
 
 impl Board {
     pub fn new() -> Self {
-        Self {
-            pieces: Vec::new(),
+        let mut board = Self {
+            mailbox: [[None; NUM_SQUARES]; 7],
+            piece_boards: Default::default(),
             captured_pieces: Vec::new(),
             board_set: [
                 (BoardType::White, Level::White),
@@ -52,9 +86,89 @@ impl Board {
                 (BoardType::BlackQueen, Level::QL6),
                 (BoardType::BlackKing, Level::KL6),
             ],
+            en_passant: None,
+            castling_rights: CastlingRights::all(),
             occupied_void: BitBoardSet::new(),
             occupied_piece: ColorMask::new(),
+            ray_occupied: BitBoard::EMPTY,
+            hash: 0,
+        };
+
+        board.update();
+
+        board
+    }
+
+    /// 보드 위의 모든 기물, 보드와 Square 순서로 반환합니다.
+    pub fn pieces(&self) -> impl Iterator<Item = &Piece> {
+        self.mailbox.iter().flatten().flatten()
+    }
+
+    /// color 색 piece_type 기물이 있는 Square
+    pub fn get_piece_board(&self, color: Color, piece_type: PieceType) -> &BitBoardSet {
+        &self.piece_boards[color as usize][piece_type as usize]
+    }
+
+    /// Level이 포함된 Square가 놓인 보드와 Square 번호
+    fn get_mailbox_index(&self, square: BitBoard) -> Option<(BoardType, usize)> {
+        if square.remove_level().is_empty() {
+            return None;
         }
+
+        let board_type = self.convert_board_type(square.get_level())?;
+
+        Some((board_type, square.get_index()))
+    }
+
+    /// 해시를 처음부터 다시 계산합니다.
+    pub fn compute_hash(&self) -> u64 {
+        let mut hash = zobrist::castling(self.castling_rights);
+
+        for (board_type, level) in self.board_set.iter() {
+            hash ^= zobrist::board(*board_type, *level);
+        }
+
+        for piece in self.pieces() {
+            hash ^= zobrist::piece(piece.color, piece.piece_type, piece.position);
+        }
+
+        if let Some(en_passant) = self.en_passant {
+            hash ^= zobrist::en_passant(en_passant.square);
+        }
+
+        hash
+    }
+
+    /// 보드가 없는 Level의 기물은 놓지 않습니다.
+    fn add_piece(&mut self, piece: Piece) {
+        let (board_type, index) = match self.get_mailbox_index(piece.position) {
+            Some(mailbox_index) => mailbox_index,
+            None => return,
+        };
+        let square = piece.position.remove_level();
+
+        self.piece_boards[piece.color as usize][piece.piece_type as usize][board_type] |= square;
+        self.occupied_piece[piece.color][board_type] |= square;
+        self.ray_occupied = Piece::compute_ray_occupied(self);
+        self.hash ^= zobrist::piece(piece.color, piece.piece_type, piece.position);
+        self.mailbox[board_type as usize][index] = Some(piece);
+    }
+
+    fn set_castling_rights(&mut self, castling_rights: CastlingRights) {
+        self.hash ^= zobrist::castling(self.castling_rights) ^ zobrist::castling(castling_rights);
+        self.castling_rights = castling_rights;
+    }
+
+    fn set_en_passant(&mut self, en_passant: Option<EnPassant>) {
+        if let Some(old) = self.en_passant {
+            self.hash ^= zobrist::en_passant(old.square);
+        }
+
+        if let Some(new) = en_passant {
+            self.hash ^= zobrist::en_passant(new.square);
+        }
+
+        self.en_passant = en_passant;
     }
 
     pub fn convert_board_type(&self, level: Level) -> Option<BoardType> {
@@ -62,10 +176,11 @@ impl Board {
             Level::White => Some(BoardType::White),
             Level::Neutral => Some(BoardType::Neutral),
             Level::Black => Some(BoardType::Black),
-            _ => match self.board_set.iter().find(|(_, l)| *l == level) {
-                Some((board_type, _)) => Some(*board_type),
-                None => None,
-            },
+            _ => self
+                .board_set
+                .iter()
+                .find(|(_, l)| *l == level)
+                .map(|(board_type, _)| *board_type),
         }
     }
 
@@ -75,7 +190,11 @@ impl Board {
 
     /// 같은 Rank, File의 모든 Square 상태를 반환합니다.
     /// TODO: 함수 이름 변경, 반환값 정리
-    pub fn get_empty_board(&self, squares: BitBoard, ignore_color: Option<Color>) -> Vec<(BoardType, BitBoard, bool)> {
+    pub fn get_empty_board(
+        &self,
+        squares: BitBoard,
+        ignore_color: Option<Color>,
+    ) -> Vec<(BoardType, BitBoard, bool)> {
         let mut result = Vec::new();
 
         let squares = squares.remove_level();
@@ -105,6 +224,35 @@ impl Board {
         result
     }
 
+    /// get_empty_board와 같은 판정을 보드별 (비어 있는 Square, 기물이 있는 Square)로 반환합니다.
+    ///
+    /// ignore_color 색의 기물은 없는 것으로 봅니다. 메모리를 할당하지 않습니다.
+    pub fn split_empty_squares(
+        &self,
+        squares: BitBoard,
+        ignore_color: Option<Color>,
+    ) -> (BitBoardSet, BitBoardSet) {
+        let squares = squares.remove_level();
+        let mut empty = BitBoardSet::new();
+        let mut occupied = BitBoardSet::new();
+
+        for board_type in BoardType::iter() {
+            let area = squares & self.convert_level(board_type).get_bit_board();
+            let pieces = match ignore_color {
+                Some(color) => self.occupied_piece[!color][board_type],
+                None => {
+                    self.occupied_piece[Color::White][board_type]
+                        | self.occupied_piece[Color::Black][board_type]
+                }
+            };
+
+            empty[board_type] = area.difference(pieces);
+            occupied[board_type] = area & pieces;
+        }
+
+        (empty, occupied)
+    }
+
     pub fn get_empty_board_with_color(
         &self,
         square: BitBoard,
@@ -150,24 +298,28 @@ impl Board {
     }
 
     pub fn get_piece(&self, square: BitBoard) -> Option<&Piece> {
-        self.pieces.iter().find(|piece| piece.position == square)
+        let (board_type, index) = self.get_mailbox_index(square)?;
+
+        self.mailbox[board_type as usize][index].as_ref()
     }
 
     pub fn remove_piece(&mut self, square: BitBoard) -> Option<Piece> {
-        match self
-            .pieces
-            .iter()
-            .position(|piece| piece.position == square)
-        {
-            Some(index) => Some(self.pieces.remove(index)),
-            None => None,
-        }
+        let (board_type, index) = self.get_mailbox_index(square)?;
+        let piece = self.mailbox[board_type as usize][index].take()?;
+        let square = square.remove_level();
+
+        self.piece_boards[piece.color as usize][piece.piece_type as usize][board_type] &= !square;
+        self.occupied_piece[piece.color][board_type] &= !square;
+        self.ray_occupied = Piece::compute_ray_occupied(self);
+        self.hash ^= zobrist::piece(piece.color, piece.piece_type, piece.position);
+
+        Some(piece)
     }
 
     pub fn set_piece(&mut self, square: BitBoard, piece: PieceType, color: Color) -> Option<Piece> {
         let old_piece = self.remove_piece(square);
 
-        self.pieces.push(Piece::new(square, piece, color));
+        self.add_piece(Piece::new(square, piece, color));
 
         old_piece
     }
@@ -176,25 +328,461 @@ impl Board {
         &mut self,
         source: BitBoard,
         destination: BitBoard,
-    ) -> Result<(), &'static str> {
+        promotion: Option<PieceType>,
+    ) -> Result<(), WarpSquareError> {
         let mut piece = match self.remove_piece(source) {
             Some(piece) => piece,
-            None => return Err("There is no piece at the source"),
+            None => return Err(WarpSquareError::NoPieceAtSource),
         };
 
-        let _captured_piece = match self.remove_piece(destination) {
-            Some(piece) => self.captured_pieces.push(piece),
-            None => (),
-        };
+        let en_passant = self.get_en_passant(piece.color);
+        self.set_en_passant(None);
+
+        if self.is_castling(&piece, destination) {
+            // 킹과 룩의 자리를 바꿈
+            if let Some(mut rook) = self.remove_piece(destination) {
+                rook.position = source;
+                rook.is_moved = true;
+
+                self.add_piece(rook);
+            }
+        } else {
+            match self.remove_piece(destination) {
+                Some(captured_piece) => self.captured_pieces.push(captured_piece),
+                None => {
+                    if let Some(en_passant) = en_passant {
+                        if Self::is_en_passant(&piece, source, destination, &en_passant) {
+                            if let Some(captured_piece) = self.remove_piece(en_passant.pawn) {
+                                self.captured_pieces.push(captured_piece);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        self.update_castling_rights(source, destination);
+
+        if piece.piece_type == PieceType::Pawn
+            && (source.get_rank() as i8 - destination.get_rank() as i8).abs() == 2
+        {
+            self.set_en_passant(Some(EnPassant {
+                square: source.remove_level().forward(piece.color),
+                pawn: destination,
+            }));
+        }
 
         piece.position = destination;
         piece.is_moved = true;
 
-        self.pieces.push(piece);
+        if let Some(piece_type) = promotion {
+            piece.piece_type = piece_type;
+        }
+
+        self.add_piece(piece);
+
+        Ok(())
+    }
+
+    /// color 색의 폰이 destination에 도착하면 승격해야 하는지 여부
+    ///
+    /// 상대 진영 끝의 랭크 중 도착한 Level에서 더 전진할 수 없는 Square가 승격 Square입니다.
+    pub fn is_promotion_square(destination: BitBoard, color: Color) -> bool {
+        let is_last_ranks = match color {
+            Color::White => destination.get_rank() >= Rank::Eight,
+            Color::Black => destination.get_rank() <= Rank::One,
+        };
+        let forward = destination.remove_level().forward(color);
+
+        is_last_ranks
+            && (forward.is_empty() || !destination.get_level().get_bit_board().contains(forward))
+    }
+
+    /// 킹이 같은 색의 룩 자리로 움직이는지 여부
+    pub fn is_castling(&self, piece: &Piece, destination: BitBoard) -> bool {
+        match self.get_piece(destination) {
+            Some(rook) => {
+                piece.piece_type == PieceType::King
+                    && rook.piece_type == PieceType::Rook
+                    && rook.color == piece.color
+            }
+            None => false,
+        }
+    }
+
+    /// 킹과 룩 사이에 있는 Square, Level 영역은 비어 있습니다.
+    fn get_castling_path(king: BitBoard, rook: BitBoard) -> BitBoard {
+        let rank = king.get_rank();
+        let king_file = king.get_file() as u8;
+        let rook_file = rook.get_file() as u8;
+
+        (king_file.min(rook_file) + 1..king_file.max(rook_file))
+            .map(|file| {
+                BitBoard::from_square(&Square::new(rank, File::from_u8(file), Level::White))
+            })
+            .fold(BitBoard::EMPTY, |acc, square| acc | square.remove_level())
+    }
+
+    /// king 위치의 킹과 rook 위치의 룩이 캐슬링할 수 있는지 여부
+    ///
+    /// 킹이 체크 상태이거나 킹과 룩 사이의 Square가 막혀 있거나 공격받고 있으면 할 수 없습니다.
+    /// 캐슬링 후 킹이 공격받는지는 검사하지 않습니다.
+    pub fn can_castle(&self, king: BitBoard, rook: BitBoard) -> bool {
+        let rights = match CastlingRights::SQUARES
+            .iter()
+            .find(|(_, king_square, rook_square)| *king_square == king && *rook_square == rook)
+        {
+            Some((rights, _, _)) => *rights,
+            None => return false,
+        };
+
+        if !self.castling_rights.contains(rights) {
+            return false;
+        }
+
+        let color = match (self.get_piece(king), self.get_piece(rook)) {
+            (Some(king_piece), Some(rook_piece)) => {
+                if king_piece.piece_type != PieceType::King
+                    || rook_piece.piece_type != PieceType::Rook
+                    || king_piece.color != rook_piece.color
+                    || king_piece.is_moved
+                    || rook_piece.is_moved
+                {
+                    return false;
+                }
+
+                king_piece.color
+            }
+            _ => return false,
+        };
+
+        if self.is_attacked(king, !color) {
+            return false;
+        }
+
+        let path = Self::get_castling_path(king, rook);
+
+        let (empty, occupied) = self.split_empty_squares(path, None);
+
+        if !occupied.union().is_empty() {
+            return false;
+        }
+
+        BoardType::iter().all(|board_type| {
+            let level = self.convert_level(board_type).into_bit_board();
+
+            empty[board_type]
+                .iter()
+                .all(|square| !self.is_attacked(square | level, !color))
+        })
+    }
+
+    /// color 색이 할 수 있는 캐슬링의 (킹 위치, 룩 위치)
+    pub fn get_castling_moves(
+        &self,
+        color: Color,
+    ) -> impl Iterator<Item = (BitBoard, BitBoard)> + '_ {
+        CastlingRights::SQUARES
+            .iter()
+            .filter(move |(rights, _, _)| CastlingRights::from_color(color).contains(*rights))
+            .filter(|(_, king, rook)| self.can_castle(*king, *rook))
+            .map(|(_, king, rook)| (*king, *rook))
+    }
+
+    /// 킹이나 룩이 시작 위치를 떠나거나 룩이 잡히면 캐슬링 권리를 잃음
+    fn update_castling_rights(&mut self, source: BitBoard, destination: BitBoard) {
+        let mut castling_rights = self.castling_rights;
+
+        for (rights, king, rook) in CastlingRights::SQUARES {
+            if source == king || source == rook || destination == rook {
+                castling_rights.remove(rights);
+            }
+        }
+
+        self.set_castling_rights(castling_rights);
+    }
+
+    /// color 색이 앙파상으로 잡을 수 있는 폰 정보
+    pub fn get_en_passant(&self, color: Color) -> Option<EnPassant> {
+        let en_passant = self.en_passant?;
+
+        match self.get_piece(en_passant.pawn) {
+            Some(pawn) if pawn.color != color => Some(en_passant),
+            _ => None,
+        }
+    }
+
+    /// 폰이 대각선으로 앙파상 Square에 들어가는지 여부
+    fn is_en_passant(
+        piece: &Piece,
+        source: BitBoard,
+        destination: BitBoard,
+        en_passant: &EnPassant,
+    ) -> bool {
+        piece.piece_type == PieceType::Pawn
+            && source.get_file() != destination.get_file()
+            && destination.remove_level() == en_passant.square
+    }
+
+    /// 해당 보드 위에 있는 기물 수
+    fn count_board_pieces(&self, board_type: BoardType) -> u32 {
+        (self.occupied_piece[Color::White][board_type]
+            | self.occupied_piece[Color::Black][board_type])
+            .bits()
+            .count_ones()
+    }
+
+    /// 공격 보드를 움직일 수 있는 색
+    ///
+    /// 기물이 하나 있으면 그 기물의 색, 비어 있으면 보드의 주인입니다.
+    /// 기물이 둘 이상 있으면 움직일 수 없습니다.
+    pub fn get_board_controller(&self, board_type: BoardType) -> Option<Color> {
+        match self.count_board_pieces(board_type) {
+            0 => board_type.get_owner(),
+            1 => self.mailbox[board_type as usize]
+                .iter()
+                .flatten()
+                .next()
+                .map(|piece| piece.color),
+            _ => None,
+        }
+    }
+
+    pub fn validate_board_move(
+        &self,
+        source: Level,
+        destination: Level,
+    ) -> Result<BoardType, WarpSquareError> {
+        // 공격 보드는 핀 사이에서만 움직임
+        if source.get_pin_number().is_none() {
+            return Err(WarpSquareError::NoAttackBoard);
+        }
+
+        if destination.get_pin_number().is_none() {
+            return Err(WarpSquareError::PinNotAdjacent);
+        }
+
+        let board_type = match self.convert_board_type(source) {
+            Some(board_type) => board_type,
+            None => return Err(WarpSquareError::NoAttackBoard),
+        };
+
+        if self.convert_board_type(destination).is_some() {
+            return Err(WarpSquareError::PinOccupied);
+        }
+
+        if !source.get_adjacent_pins().any(|pin| pin == destination) {
+            return Err(WarpSquareError::PinNotAdjacent);
+        }
+
+        let controller = match self.get_board_controller(board_type) {
+            Some(color) => color,
+            None => return Err(WarpSquareError::BoardOverloaded),
+        };
+
+        // 기물을 태운 보드는 뒤로 움직일 수 없음
+        let source_rank = source.get_bit_board().get_rank();
+        let destination_rank = destination.get_bit_board().get_rank();
+        let is_backward = match controller {
+            Color::White => destination_rank < source_rank,
+            Color::Black => destination_rank > source_rank,
+        };
+
+        if is_backward && self.count_board_pieces(board_type) > 0 {
+            return Err(WarpSquareError::BoardMoveBackward);
+        }
+
+        Ok(board_type)
+    }
+
+    /// 해당 핀에서 이동할 수 있는 핀 목록
+    pub fn get_board_destinations(&self, source: Level) -> impl Iterator<Item = Level> + '_ {
+        source
+            .get_adjacent_pins()
+            .filter(move |destination| self.validate_board_move(source, *destination).is_ok())
+    }
+
+    /// 공격 보드를 다른 핀으로 옮깁니다. 보드 위의 기물도 함께 이동합니다.
+    pub fn move_board(&mut self, source: Level, destination: Level) -> Result<(), WarpSquareError> {
+        let board_type = self.validate_board_move(source, destination)?;
+
+        self.relocate_board(board_type, source, destination);
+        self.set_en_passant(None);
+
+        // 보드와 함께 시작 위치를 떠난 킹, 룩은 캐슬링할 수 없음
+        let mut castling_rights = self.castling_rights;
+
+        for (rights, king, rook) in CastlingRights::SQUARES {
+            if king.get_level() == source || rook.get_level() == source {
+                castling_rights.remove(rights);
+            }
+        }
+
+        self.set_castling_rights(castling_rights);
 
         Ok(())
     }
 
+    /// 검사 없이 보드와 그 위의 기물을 옮깁니다.
+    fn relocate_board(&mut self, board_type: BoardType, source: Level, destination: Level) {
+        let pieces = self.mailbox[board_type as usize];
+
+        for piece in pieces.iter().flatten() {
+            self.remove_piece(piece.position);
+        }
+
+        self.board_set[board_type as usize].1 = destination;
+        self.occupied_void[board_type] = !destination.get_bit_board();
+        self.ray_occupied = Piece::compute_ray_occupied(self);
+        self.hash ^= zobrist::board(board_type, source) ^ zobrist::board(board_type, destination);
+
+        for mut piece in pieces.into_iter().flatten() {
+            let square = piece.position.remove_level();
+
+            if let Some((_, new_square)) = source
+                .get_bit_board()
+                .iter()
+                .zip(destination.get_bit_board().iter())
+                .find(|(old, _)| *old == square)
+            {
+                piece.position = new_square | destination.into_bit_board();
+            }
+
+            self.add_piece(piece);
+        }
+    }
+
+    /// 수를 보드에 적용하고 unmake_move로 되돌릴 기록을 반환합니다. 합법 여부는 검사하지 않습니다.
+    pub fn make_move(&mut self, piece_move: &PieceMove) -> Result<MoveUndo, WarpSquareError> {
+        let mut undo = MoveUndo {
+            piece: None,
+            rook: None,
+            is_capture: false,
+            en_passant: self.en_passant,
+            castling_rights: self.castling_rights,
+            hash: self.hash,
+        };
+
+        match piece_move.move_type {
+            MoveType::Move => {
+                let source = BitBoard::from_square(&piece_move.source);
+                let destination = BitBoard::from_square(&piece_move.destination);
+
+                let piece = match self.get_piece(source) {
+                    Some(piece) => *piece,
+                    None => return Err(WarpSquareError::NoPieceAtSource),
+                };
+
+                if self.is_castling(&piece, destination) {
+                    undo.rook = self.get_piece(destination).copied();
+                }
+
+                undo.piece = Some(piece);
+
+                let captured_count = self.captured_pieces.len();
+
+                self.move_piece(source, destination, piece_move.promotion)?;
+
+                undo.is_capture = self.captured_pieces.len() != captured_count;
+            }
+            MoveType::BoardMove => {
+                self.move_board(piece_move.source.level, piece_move.destination.level)?
+            }
+        }
+
+        Ok(undo)
+    }
+
+    /// make_move로 둔 수를 되돌립니다. piece_move는 가장 최근에 둔 수여야 합니다.
+    pub fn unmake_move(&mut self, piece_move: &PieceMove, undo: MoveUndo) {
+        match piece_move.move_type {
+            MoveType::Move => {
+                let source = BitBoard::from_square(&piece_move.source);
+                let destination = BitBoard::from_square(&piece_move.destination);
+
+                self.remove_piece(destination);
+
+                if let Some(rook) = undo.rook {
+                    self.remove_piece(source);
+                    self.add_piece(rook);
+                }
+
+                if undo.is_capture {
+                    if let Some(captured_piece) = self.captured_pieces.pop() {
+                        self.add_piece(captured_piece);
+                    }
+                }
+
+                if let Some(piece) = undo.piece {
+                    self.add_piece(piece);
+                }
+            }
+            MoveType::BoardMove => {
+                let source = piece_move.source.level;
+                let destination = piece_move.destination.level;
+
+                if let Some(board_type) = self.convert_board_type(destination) {
+                    self.relocate_board(board_type, destination, source);
+                }
+            }
+        }
+
+        self.en_passant = undo.en_passant;
+        self.castling_rights = undo.castling_rights;
+        self.hash = undo.hash;
+    }
+
+    /// 수를 둔 뒤 color 색의 킹이 공격받는지 여부, 보드는 원래대로 되돌립니다.
+    pub fn leaves_king_in_check(&mut self, piece_move: &PieceMove, color: Color) -> bool {
+        match self.make_move(piece_move) {
+            Ok(undo) => {
+                let is_check = self.is_check(color);
+
+                self.unmake_move(piece_move, undo);
+
+                is_check
+            }
+            Err(_) => true,
+        }
+    }
+
+    pub fn get_king(&self, color: Color) -> Option<&Piece> {
+        let kings = self.get_piece_board(color, PieceType::King);
+
+        BoardType::iter()
+            .find(|board_type| !kings[*board_type].is_empty())
+            .and_then(|board_type| {
+                self.mailbox[board_type as usize][kings[board_type].get_index()].as_ref()
+            })
+    }
+
+    /// 해당 Square가 attacker 색의 기물에게 공격받고 있는지 여부
+    pub fn is_attacked(&self, square: BitBoard, attacker: Color) -> bool {
+        let board_type = match self.convert_board_type(square.get_level()) {
+            Some(board_type) => board_type,
+            None => return false,
+        };
+        let square = square.remove_level();
+
+        self.pieces()
+            .filter(|piece| piece.color == attacker)
+            .any(|piece| match piece.piece_type {
+                // 폰은 대각선 앞만 공격함
+                PieceType::Pawn => {
+                    attack_table::get_pawn_attacks(piece.position, piece.color).contains(square)
+                }
+                _ => piece.compute_attacks(self)[board_type].contains(square),
+            })
+    }
+
+    /// color 색의 킹이 체크 상태인지 여부
+    pub fn is_check(&self, color: Color) -> bool {
+        match self.get_king(color) {
+            Some(king) => self.is_attacked(king.position, !color),
+            None => false,
+        }
+    }
+
     pub fn validate_square(&self, square: BitBoard) -> bool {
         let level = BitBoard::into_square(&square).level;
         let square = square.remove_level();
@@ -210,25 +798,30 @@ impl Board {
             self.occupied_void[board_type] = !self.convert_level(board_type).get_bit_board();
         }
 
-        for piece in self.pieces.iter() {
-            match self.convert_board_type(piece.position.get_level()) {
-                Some(board_type) => {
-                    self.occupied_piece[piece.color][board_type] |= piece.position;
-                }
-                None => (),
+        self.piece_boards = Default::default();
+
+        for board_type in BoardType::iter() {
+            for piece in self.mailbox[board_type as usize].iter().flatten() {
+                let square = piece.position.remove_level();
+
+                self.piece_boards[piece.color as usize][piece.piece_type as usize][board_type] |=
+                    square;
+                self.occupied_piece[piece.color][board_type] |= square;
             }
         }
+
+        self.ray_occupied = Piece::compute_ray_occupied(self);
     }
 
+    /// 기물 배치로부터 점유 상태와 해시를 다시 계산합니다.
     pub fn update(&mut self) {
         self.update_occupied();
+        self.hash = self.compute_hash();
+    }
+}
 
-        let mut pieces = self.pieces.clone();
-
-        for piece in pieces.iter_mut() {
-            piece.update_attacks(&self);
-        }
-
-        self.pieces = pieces;
+impl Default for Board {
+    fn default() -> Self {
+        Self::new()
     }
 }
@@ -0,0 +1,52 @@
+use bitflags::bitflags;
+
+use crate::{bit_board::BitBoard, square::Color};
+
+bitflags! {
+    #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug, Hash)]
+    pub struct CastlingRights: u8 {
+        const WHITE_KING_SIDE = 1;
+        const WHITE_QUEEN_SIDE = 1 << 1;
+        const BLACK_KING_SIDE = 1 << 2;
+        const BLACK_QUEEN_SIDE = 1 << 3;
+
+        const WHITE = Self::WHITE_KING_SIDE.bits() | Self::WHITE_QUEEN_SIDE.bits();
+        const BLACK = Self::BLACK_KING_SIDE.bits() | Self::BLACK_QUEEN_SIDE.bits();
+    }
+}
+
+impl CastlingRights {
+    /// 캐슬링 종류별 (권리, 킹 시작 위치, 룩 시작 위치)
+    ///
+    /// 킹 사이드는 킹 공격 보드 위의 킹과 룩이 자리를 바꿉니다.
+    /// 퀸 사이드는 킹과 퀸 공격 보드 위의 룩이 자리를 바꾸며, 룩이 반대편 공격 보드로 건너갑니다.
+    pub const SQUARES: [(CastlingRights, BitBoard, BitBoard); 4] = [
+        (
+            Self::WHITE_KING_SIDE,
+            BitBoard::from_bits_retain(BitBoard::D0.bits() | BitBoard::KL1.bits()),
+            BitBoard::from_bits_retain(BitBoard::E0.bits() | BitBoard::KL1.bits()),
+        ),
+        (
+            Self::WHITE_QUEEN_SIDE,
+            BitBoard::from_bits_retain(BitBoard::D0.bits() | BitBoard::KL1.bits()),
+            BitBoard::from_bits_retain(BitBoard::Z0.bits() | BitBoard::QL1.bits()),
+        ),
+        (
+            Self::BLACK_KING_SIDE,
+            BitBoard::from_bits_retain(BitBoard::D9.bits() | BitBoard::KL6.bits()),
+            BitBoard::from_bits_retain(BitBoard::E9.bits() | BitBoard::KL6.bits()),
+        ),
+        (
+            Self::BLACK_QUEEN_SIDE,
+            BitBoard::from_bits_retain(BitBoard::D9.bits() | BitBoard::KL6.bits()),
+            BitBoard::from_bits_retain(BitBoard::Z9.bits() | BitBoard::QL6.bits()),
+        ),
+    ];
+
+    pub fn from_color(color: Color) -> Self {
+        match color {
+            Color::White => Self::WHITE,
+            Color::Black => Self::BLACK,
+        }
+    }
+}
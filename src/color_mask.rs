@@ -19,6 +19,12 @@ impl ColorMask {
     }
 }
 
+impl Default for ColorMask {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Index<Color> for ColorMask {
     type Output = BitBoardSet;
 
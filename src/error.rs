@@ -0,0 +1,49 @@
+use std::{error::Error, fmt};
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub enum WarpSquareError {
+    NoPieceAtSource,
+    WrongSideToMove,
+    IllegalDestination,
+    KingInCheck,
+    /// Square가 해당 Level에 없거나, 그 Level에 보드가 놓여 있지 않음
+    InvalidSquareForLevel,
+    PromotionRequired,
+    InvalidPromotion,
+    NoAttackBoard,
+    PinOccupied,
+    PinNotAdjacent,
+    BoardOverloaded,
+    BoardMoveBackward,
+    GameOver,
+    NothingToUndo,
+    /// 해석할 수 없는 표기
+    InvalidNotation(String),
+    /// 테이블베이스로 만들 수 없는 기물 구성
+    UnsupportedTablebase,
+}
+
+impl fmt::Display for WarpSquareError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoPieceAtSource => write!(f, "There is no piece at the source"),
+            Self::WrongSideToMove => write!(f, "It is not the turn of the piece"),
+            Self::IllegalDestination => write!(f, "The piece cannot move to the destination"),
+            Self::KingInCheck => write!(f, "The move leaves the king in check"),
+            Self::InvalidSquareForLevel => write!(f, "The square does not exist on the level"),
+            Self::PromotionRequired => write!(f, "The pawn must be promoted"),
+            Self::InvalidPromotion => write!(f, "The promotion is not allowed"),
+            Self::NoAttackBoard => write!(f, "There is no attack board at the source"),
+            Self::PinOccupied => write!(f, "The destination pin is occupied"),
+            Self::PinNotAdjacent => write!(f, "The destination pin is not adjacent"),
+            Self::BoardOverloaded => write!(f, "The attack board carries more than one piece"),
+            Self::BoardMoveBackward => write!(f, "An occupied attack board cannot move backward"),
+            Self::GameOver => write!(f, "The game is over"),
+            Self::NothingToUndo => write!(f, "Nothing to pop"),
+            Self::InvalidNotation(notation) => write!(f, "Invalid notation: {}", notation),
+            Self::UnsupportedTablebase => write!(f, "The tablebase does not support the pieces"),
+        }
+    }
+}
+
+impl Error for WarpSquareError {}
@@ -0,0 +1,288 @@
+use std::fmt;
+
+use crate::{
+    attack_table,
+    bit_board::{BitBoard, BitBoardSet, BoardType},
+    board::Board,
+    piece::{PieceType, NUM_PIECES},
+    square::{Color, NUM_FILES, NUM_RANKS},
+};
+
+/// 평가 항목별 가중치, 단위는 센티폰입니다.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct EvalWeights {
+    /// 기물 종류별 가치
+    pub piece_values: [i32; NUM_PIECES],
+    /// 중립 보드에서 공격하는 Square 하나의 점수
+    pub neutral_control: i32,
+    /// BoardType별로 기물이 움직일 수 있는 Square 하나의 점수
+    pub mobility: [i32; 7],
+    /// 움직일 수 있는 공격 보드 하나의 점수
+    pub attack_board_control: i32,
+    /// 공격 보드 위에서 상대에게 공격받는 기물 하나의 감점
+    pub attack_board_threat: i32,
+    /// 킹 주변에서 상대가 공격하는 Square 하나의 감점, 모든 Level을 따로 셉니다.
+    pub king_exposure: i32,
+    pub doubled_pawn: i32,
+    pub isolated_pawn: i32,
+    pub passed_pawn: i32,
+}
+
+impl EvalWeights {
+    pub fn new() -> Self {
+        Self {
+            piece_values: [100, 300, 300, 500, 900, 0],
+            neutral_control: 4,
+            mobility: [2, 3, 2, 3, 3, 3, 3],
+            attack_board_control: 15,
+            attack_board_threat: 20,
+            king_exposure: 10,
+            doubled_pawn: 15,
+            isolated_pawn: 10,
+            passed_pawn: 20,
+        }
+    }
+
+    pub fn get_piece_value(&self, piece_type: PieceType) -> i32 {
+        self.piece_values[piece_type as usize]
+    }
+}
+
+impl Default for EvalWeights {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 항목별 평가 점수, 모두 백의 입장에서 본 점수입니다.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash, Default)]
+pub struct Evaluation {
+    pub material: i32,
+    pub neutral_control: i32,
+    pub mobility: i32,
+    pub attack_board_safety: i32,
+    pub king_exposure: i32,
+    pub pawn_structure: i32,
+}
+
+impl Evaluation {
+    pub fn get_total(&self) -> i32 {
+        self.material
+            + self.neutral_control
+            + self.mobility
+            + self.attack_board_safety
+            + self.king_exposure
+            + self.pawn_structure
+    }
+
+    /// color 입장에서 본 전체 점수
+    pub fn get_score(&self, color: Color) -> i32 {
+        match color {
+            Color::White => self.get_total(),
+            Color::Black => -self.get_total(),
+        }
+    }
+}
+
+impl fmt::Display for Evaluation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Material: {}", self.material)?;
+        writeln!(f, "Neutral control: {}", self.neutral_control)?;
+        writeln!(f, "Mobility: {}", self.mobility)?;
+        writeln!(f, "Attack board safety: {}", self.attack_board_safety)?;
+        writeln!(f, "King exposure: {}", self.king_exposure)?;
+        writeln!(f, "Pawn structure: {}", self.pawn_structure)?;
+        write!(f, "Total: {}", self.get_total())
+    }
+}
+
+const ATTACK_BOARDS: [BoardType; 4] = [
+    BoardType::WhiteQueen,
+    BoardType::WhiteKing,
+    BoardType::BlackQueen,
+    BoardType::BlackKing,
+];
+
+/// 한 색의 기물이 보드별로 움직일 수 있는 Square와 공격하는 Square
+struct Activity {
+    mobility: i32,
+    control: BitBoardSet,
+}
+
+impl Activity {
+    /// 폰은 전진하는 Square로 기동력을, 대각선 앞 Square로 공격을 셉니다.
+    fn new(board: &Board, color: Color, weights: &EvalWeights) -> Self {
+        let mut mobility = 0;
+        let mut control = BitBoardSet::new();
+
+        for piece in board.pieces().filter(|piece| piece.color == color) {
+            let attacks = piece.compute_attacks(board);
+
+            for board_type in BoardType::iter() {
+                mobility += attacks[board_type].bits().count_ones() as i32
+                    * weights.mobility[board_type as usize];
+            }
+
+            match piece.piece_type {
+                PieceType::Pawn => {
+                    let destination = attack_table::get_pawn_attacks(piece.position, color);
+
+                    for board_type in BoardType::iter() {
+                        control[board_type] |= destination & get_board_area(board, board_type);
+                    }
+                }
+                _ => control = control | attacks,
+            }
+        }
+
+        Self { mobility, control }
+    }
+}
+
+fn get_board_area(board: &Board, board_type: BoardType) -> BitBoard {
+    board.convert_level(board_type).get_bit_board()
+}
+
+/// 공격 보드를 움직일 수 있으면 점수를 얻고, 그 위의 기물이 공격받으면 점수를 잃습니다.
+fn get_attack_board_safety(
+    board: &Board,
+    color: Color,
+    opponent: &Activity,
+    weights: &EvalWeights,
+) -> i32 {
+    let mut score = 0;
+
+    for board_type in ATTACK_BOARDS {
+        if board.get_board_controller(board_type) == Some(color) {
+            score += weights.attack_board_control;
+        }
+
+        let pieces = board.occupied_piece[color][board_type];
+        let threatened = pieces & opponent.control[board_type];
+
+        score -= threatened.bits().count_ones() as i32 * weights.attack_board_threat;
+    }
+
+    score
+}
+
+/// 킹과 인접한 Square 중 상대가 공격하는 Square, 킹 위아래의 다른 Level도 포함합니다.
+fn get_king_exposure(
+    board: &Board,
+    color: Color,
+    opponent: &Activity,
+    weights: &EvalWeights,
+) -> i32 {
+    let king = match board.get_king(color) {
+        Some(king) => king,
+        None => return 0,
+    };
+
+    let zone = attack_table::get_king_attacks(king.position) | king.position.remove_level();
+
+    let exposed: u32 = BoardType::iter()
+        .map(|board_type| (opponent.control[board_type] & zone).bits().count_ones())
+        .sum();
+
+    -(exposed as i32) * weights.king_exposure
+}
+
+/// (파일, 랭크) 격자에서 color 쪽으로 앞에 있는 같은 파일과 옆 파일의 Square
+fn get_front_span(file: usize, rank: usize, color: Color) -> BitBoard {
+    let mut span = BitBoard::EMPTY;
+
+    for span_file in file.saturating_sub(1)..=(file + 1).min(NUM_FILES as usize - 1) {
+        let ranks = match color {
+            Color::White => rank + 1..NUM_RANKS as usize,
+            Color::Black => 0..rank,
+        };
+
+        for span_rank in ranks {
+            span |= BitBoard::from_bits_retain(1 << (span_rank + span_file * NUM_RANKS as usize));
+        }
+    }
+
+    span
+}
+
+/// 모든 Level의 폰을 (파일, 랭크) 격자에 겹쳐 겹친 폰, 고립된 폰, 통과한 폰을 셉니다.
+fn get_pawn_structure(board: &Board, color: Color, weights: &EvalWeights) -> i32 {
+    let pawns: Vec<BitBoard> = board
+        .get_piece_board(color, PieceType::Pawn)
+        .iter()
+        .flat_map(|squares| squares.iter())
+        .collect();
+    let opponent_pawns = board.get_piece_board(!color, PieceType::Pawn).union();
+
+    let mut file_counts = [0; NUM_FILES as usize];
+
+    for pawn in pawns.iter() {
+        file_counts[pawn.get_file() as usize] += 1;
+    }
+
+    let mut score = 0;
+
+    for (file, count) in file_counts.iter().enumerate() {
+        if *count == 0 {
+            continue;
+        }
+
+        score -= (count - 1) * weights.doubled_pawn;
+
+        let has_neighbour = (file > 0 && file_counts[file - 1] > 0)
+            || file_counts.get(file + 1).is_some_and(|count| *count > 0);
+
+        if !has_neighbour {
+            score -= count * weights.isolated_pawn;
+        }
+    }
+
+    for pawn in pawns.iter() {
+        let file = pawn.get_file() as usize;
+        let rank = pawn.get_rank() as usize;
+
+        if (get_front_span(file, rank, color) & opponent_pawns).is_empty() {
+            score += weights.passed_pawn;
+        }
+    }
+
+    score
+}
+
+/// 보드를 백의 입장에서 항목별로 평가합니다.
+pub fn evaluate(board: &Board, weights: &EvalWeights) -> Evaluation {
+    let activities = [
+        Activity::new(board, Color::White, weights),
+        Activity::new(board, Color::Black, weights),
+    ];
+
+    let mut evaluation = Evaluation::default();
+
+    for color in Color::iter() {
+        let sign = match color {
+            Color::White => 1,
+            Color::Black => -1,
+        };
+        let activity = &activities[color as usize];
+        let opponent = &activities[!color as usize];
+
+        let material: i32 = board
+            .pieces()
+            .filter(|piece| piece.color == color)
+            .map(|piece| weights.get_piece_value(piece.piece_type))
+            .sum();
+
+        let neutral_control = activity.control[BoardType::Neutral].bits().count_ones() as i32
+            * weights.neutral_control;
+
+        evaluation.material += sign * material;
+        evaluation.neutral_control += sign * neutral_control;
+        evaluation.mobility += sign * activity.mobility;
+        evaluation.attack_board_safety +=
+            sign * get_attack_board_safety(board, color, opponent, weights);
+        evaluation.king_exposure += sign * get_king_exposure(board, color, opponent, weights);
+        evaluation.pawn_structure += sign * get_pawn_structure(board, color, weights);
+    }
+
+    evaluation
+}
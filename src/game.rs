@@ -1,15 +1,90 @@
+use std::sync::Arc;
+
 use crate::{
-    bit_board::BitBoard,
-    board::{Board, BoardSnapshot},
-    piece::PieceType,
-    piece_move::PieceMove,
+    bit_board::{BitBoard, BoardType},
+    board::{Board, MoveUndo},
+    error::WarpSquareError,
+    eval::{self, EvalWeights, Evaluation},
+    opening_book::OpeningBook,
+    piece::{PieceType, PROMOTION_PIECES},
+    piece_move::{MoveType, PieceMove},
     square::{Color, Rank, Square},
+    tablebase::{Tablebase, TablebaseEntry},
+    zobrist,
 };
 
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum GameStatus {
+    Ongoing,
+    /// 승리한 색
+    Checkmate(Color),
+    Stalemate,
+    Draw(DrawReason),
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum DrawReason {
+    /// 같은 국면이 반복됨
+    Repetition,
+    /// 폰이 움직이거나 기물을 잡지 않고 정해진 수가 지남
+    MoveRule,
+    /// 양쪽 모두 체크메이트할 기물이 없음
+    InsufficientMaterial,
+}
+
+/// 무승부 규칙, 수는 양쪽이 한 번씩 둔 것을 한 수로 셉니다.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct DrawRules {
+    /// 무승부를 요청할 수 있는 국면 반복 횟수
+    pub claim_repetitions: u32,
+    /// 자동으로 무승부가 되는 국면 반복 횟수
+    pub automatic_repetitions: u32,
+    /// 무승부를 요청할 수 있는 수
+    pub claim_moves: u32,
+    /// 자동으로 무승부가 되는 수
+    pub automatic_moves: u32,
+}
+
+impl DrawRules {
+    pub fn new() -> Self {
+        Self {
+            claim_repetitions: 3,
+            automatic_repetitions: 5,
+            claim_moves: 50,
+            automatic_moves: 75,
+        }
+    }
+}
+
+impl Default for DrawRules {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone)]
+pub struct MoveRecord {
+    pub piece_move: PieceMove,
+    pub undo: MoveUndo,
+    /// 수를 두기 전의 halfmove clock
+    pub halfmove_clock: u32,
+    /// 수를 두기 전 국면의 해시
+    pub hash: u64,
+}
+
+#[derive(Clone)]
 pub struct Game {
     pub turn: Color,
     pub board: Board,
-    pub move_stack: Vec<(PieceMove, BoardSnapshot)>,
+    pub move_stack: Vec<MoveRecord>,
+    /// 마지막으로 폰이 움직이거나 기물을 잡은 뒤 지난 수
+    pub halfmove_clock: u32,
+    /// 1부터 시작해서 흑이 수를 둘 때마다 1씩 증가
+    pub fullmove_number: u32,
+    pub draw_rules: DrawRules,
+    pub eval_weights: EvalWeights,
+    /// 기물이 적게 남았을 때 탐색의 평가에 쓰는 테이블베이스
+    pub tablebase: Option<Arc<Tablebase>>,
 }
 
 impl Game {
@@ -18,6 +93,11 @@ impl Game {
             turn: Color::White,
             board: Board::new(),
             move_stack: Vec::new(),
+            halfmove_clock: 0,
+            fullmove_number: 1,
+            draw_rules: DrawRules::new(),
+            eval_weights: EvalWeights::new(),
+            tablebase: None,
         };
 
         game.board
@@ -140,7 +220,7 @@ impl Game {
     }
 
     fn pass_turn(&mut self) {
-        let _ = self.turn != self.turn;
+        self.turn = !self.turn;
     }
 
     pub fn get_attack_squares(&self, square: &Square) -> Vec<Square> {
@@ -152,7 +232,56 @@ impl Game {
         piece.get_attack_squares(&self.board)
     }
 
-    pub fn legal_move(&self, piece_move: PieceMove) -> bool {
+    /// 차례까지 포함한 국면의 Zobrist 해시
+    pub fn get_hash(&self) -> u64 {
+        match self.turn {
+            Color::White => self.board.hash,
+            Color::Black => self.board.hash ^ zobrist::side(),
+        }
+    }
+
+    pub fn is_check(&self) -> bool {
+        self.board.is_check(self.turn)
+    }
+
+    /// 승격 기물이 올바른지 검사합니다.
+    ///
+    /// 폰이 승격 Square에 도착하면 킹, 폰을 제외한 기물로 반드시 승격해야 하고,
+    /// 그 외의 수에는 승격 기물을 지정할 수 없습니다.
+    pub fn validate_promotion(&self, piece_move: &PieceMove) -> Result<(), WarpSquareError> {
+        let destination = BitBoard::from_square(&piece_move.destination);
+        let is_promotion = match self
+            .board
+            .get_piece(BitBoard::from_square(&piece_move.source))
+        {
+            Some(piece) => {
+                !piece_move.is_board_move()
+                    && piece.piece_type == PieceType::Pawn
+                    && Board::is_promotion_square(destination, piece.color)
+            }
+            None => false,
+        };
+
+        match (is_promotion, piece_move.promotion) {
+            (true, None) => Err(WarpSquareError::PromotionRequired),
+            (true, Some(PieceType::Pawn | PieceType::King)) => {
+                Err(WarpSquareError::InvalidPromotion)
+            }
+            (false, Some(_)) => Err(WarpSquareError::InvalidPromotion),
+            _ => Ok(()),
+        }
+    }
+
+    /// 기물의 행마만 검사하고, 자신의 킹이 공격받는지는 검사하지 않습니다.
+    pub fn pseudo_legal_move(&self, piece_move: &PieceMove) -> bool {
+        if self.validate_promotion(piece_move).is_err() {
+            return false;
+        }
+
+        if piece_move.is_board_move() {
+            return self.pseudo_legal_board_move(piece_move);
+        }
+
         let bit_source = BitBoard::from_square(&piece_move.source);
         let bit_destination = BitBoard::from_square(&piece_move.destination);
 
@@ -161,44 +290,420 @@ impl Game {
             None => return false,
         };
 
-        let board_type = match self.board.convert_board_type(piece_move.source.level) {
+        if piece.color != self.turn {
+            return false;
+        }
+
+        if self.board.is_castling(piece, bit_destination) {
+            return self.board.can_castle(bit_source, bit_destination);
+        }
+
+        if !self.board.validate_square(bit_destination) {
+            return false;
+        }
+
+        // 도착 Level의 보드에 대한 공격 범위로 검사해야 다른 보드로 옮기는 수도 올바르게 판정됨
+        let board_type = match self.board.convert_board_type(piece_move.destination.level) {
             Some(board_type) => board_type,
             None => return false,
         };
 
-        if piece.attacks[board_type].contains(bit_destination.remove_level()) {
-            return true;
+        piece.compute_attacks(&self.board)[board_type].contains(bit_destination.remove_level())
+    }
+
+    fn pseudo_legal_board_move(&self, piece_move: &PieceMove) -> bool {
+        let source = piece_move.source.level;
+        let destination = piece_move.destination.level;
+
+        match self.board.validate_board_move(source, destination) {
+            Ok(board_type) => self.board.get_board_controller(board_type) == Some(self.turn),
+            Err(_) => false,
         }
+    }
 
-        false
+    pub fn legal_move(&self, piece_move: PieceMove) -> bool {
+        self.pseudo_legal_move(&piece_move)
+            && !self
+                .board
+                .clone()
+                .leaves_king_in_check(&piece_move, self.turn)
     }
 
-    pub fn push_move(&mut self, piece_move: PieceMove) -> Result<(), &'static str> {
-        let snapshot = BoardSnapshot::new(&self.board);
+    /// 현재 차례의 색이 둘 수 있는 수를 자신의 킹이 공격받는지 검사하지 않고 moves에 추가합니다.
+    fn generate_pseudo_legal_moves(&self, moves: &mut Vec<PieceMove>) {
+        for piece in self.board.pieces() {
+            if piece.color != self.turn {
+                continue;
+            }
 
-        let source = BitBoard::from_square(&piece_move.source);
-        let destination = BitBoard::from_square(&piece_move.destination);
+            let source = piece.get_square();
+            let attacks = piece.compute_attacks(&self.board);
 
-        let result = self.board.move_piece(source, destination);
-        self.board.update();
+            for board_type in BoardType::iter() {
+                let level = self.board.convert_level(board_type).into_bit_board();
 
-        self.move_stack.push((piece_move, snapshot));
-        self.pass_turn();
+                for bit_square in attacks[board_type].iter() {
+                    let destination = bit_square | level;
 
-        result
+                    if self.board.occupied_piece[piece.color][board_type].contains(bit_square) {
+                        continue;
+                    }
+
+                    if piece.piece_type == PieceType::Pawn
+                        && Board::is_promotion_square(destination, piece.color)
+                    {
+                        for promotion in PROMOTION_PIECES {
+                            moves.push(PieceMove::new(
+                                source.clone(),
+                                destination.into_square(),
+                                Some(promotion),
+                            ));
+                        }
+                    } else {
+                        moves.push(PieceMove::new(
+                            source.clone(),
+                            destination.into_square(),
+                            None,
+                        ));
+                    }
+                }
+            }
+        }
+
+        for (king, rook) in self.board.get_castling_moves(self.turn) {
+            moves.push(PieceMove::new(king.into_square(), rook.into_square(), None));
+        }
+
+        for (board_type, level) in self.board.board_set.iter() {
+            if self.board.get_board_controller(*board_type) != Some(self.turn) {
+                continue;
+            }
+
+            for destination in self.board.get_board_destinations(*level) {
+                moves.push(PieceMove::new_board_move(*level, destination));
+            }
+        }
+    }
+
+    /// 현재 차례의 색이 둘 수 있는 모든 합법적인 수
+    pub fn legal_moves(&self) -> Vec<PieceMove> {
+        let mut moves = Vec::new();
+
+        self.generate_pseudo_legal_moves(&mut moves);
+
+        let mut board = self.board.clone();
+
+        moves.retain(|piece_move| !board.leaves_king_in_check(piece_move, self.turn));
+
+        moves
+    }
+
+    /// legal_moves와 같지만 moves를 비우고 다시 채웁니다.
+    ///
+    /// 보드를 복사하지 않고 수를 두었다 되돌리며 검사하므로,
+    /// 같은 버퍼를 재사용하면 수 생성마다 메모리를 할당하지 않습니다.
+    pub fn fill_legal_moves(&mut self, moves: &mut Vec<PieceMove>) {
+        moves.clear();
+
+        self.generate_pseudo_legal_moves(moves);
+
+        let turn = self.turn;
+
+        moves.retain(|piece_move| !self.board.leaves_king_in_check(piece_move, turn));
+    }
+
+    /// 현재 차례의 색이 둘 수 있는 합법적인 수가 있는지 여부
+    fn has_legal_move(&self) -> bool {
+        let mut moves = Vec::new();
+
+        self.generate_pseudo_legal_moves(&mut moves);
+
+        let mut board = self.board.clone();
+
+        moves
+            .iter()
+            .any(|piece_move| !board.leaves_king_in_check(piece_move, self.turn))
+    }
+
+    /// 현재 국면이 지금까지 나온 횟수
+    ///
+    /// 폰이 움직이거나 기물을 잡은 뒤의 국면만 비교합니다. 공격 보드 위치도 국면에 포함됩니다.
+    pub fn get_repetition_count(&self) -> u32 {
+        let hash = self.get_hash();
+
+        let repetitions = self
+            .move_stack
+            .iter()
+            .rev()
+            .take(self.halfmove_clock as usize)
+            .filter(|record| record.hash == hash)
+            .count();
+
+        repetitions as u32 + 1
+    }
+
+    /// 양쪽 모두 체크메이트할 수 없는 기물만 남았는지 여부
+    ///
+    /// 킹만 남았거나, 나이트 하나만 남았거나, 비숍이 모두 같은 색 칸에 있는 경우입니다.
+    /// 공격 보드의 칸도 메인 보드와 같은 색 배치를 따르므로 Level과 관계없이 색을 판단합니다.
+    pub fn is_insufficient_material(&self) -> bool {
+        let mut knights = 0;
+        let mut light_bishops = 0;
+        let mut dark_bishops = 0;
+
+        for piece in self.board.pieces() {
+            match piece.piece_type {
+                PieceType::King => (),
+                PieceType::Knight => knights += 1,
+                PieceType::Bishop => {
+                    if BitBoard::LIGHT_SQUARES.contains(piece.position.remove_level()) {
+                        light_bishops += 1;
+                    } else {
+                        dark_bishops += 1;
+                    }
+                }
+                _ => return false,
+            }
+        }
+
+        match knights {
+            0 => light_bishops == 0 || dark_bishops == 0,
+            1 => light_bishops == 0 && dark_bishops == 0,
+            _ => false,
+        }
+    }
+
+    /// 오프닝 북에서 현재 국면의 수를 가중치에 따라 고릅니다. 해시가 겹친 둘 수 없는 수는 버립니다.
+    ///
+    /// random이 같으면 같은 수를 고르므로 난수 생성기를 고정하면 결과를 재현할 수 있습니다.
+    pub fn get_book_move(&self, book: &OpeningBook, random: u64) -> Option<PieceMove> {
+        let piece_move = book.choose_move(self.get_hash(), random)?;
+
+        match self.legal_move(piece_move.clone()) {
+            true => Some(piece_move.clone()),
+            false => None,
+        }
+    }
+
+    /// 테이블베이스에 있는 국면이면 현재 차례에서 본 승패와 메이트까지의 거리를 반환합니다.
+    ///
+    /// 공격 보드를 움직이지 않는다고 가정한 값이므로 실제 결과와 다를 수 있습니다.
+    pub fn probe_tablebase(&self) -> Option<TablebaseEntry> {
+        self.tablebase.as_ref()?.probe(&self.board, self.turn)
+    }
+
+    /// eval_weights로 현재 국면을 항목별로 평가합니다.
+    pub fn evaluate(&self) -> Evaluation {
+        eval::evaluate(&self.board, &self.eval_weights)
+    }
+
+    /// 자동으로 무승부가 되는 이유
+    pub(crate) fn get_automatic_draw(&self) -> Option<DrawReason> {
+        if self.is_insufficient_material() {
+            Some(DrawReason::InsufficientMaterial)
+        } else if self.get_repetition_count() >= self.draw_rules.automatic_repetitions {
+            Some(DrawReason::Repetition)
+        } else if self.halfmove_clock >= self.draw_rules.automatic_moves * 2 {
+            Some(DrawReason::MoveRule)
+        } else {
+            None
+        }
     }
 
-    pub fn pop_move(&mut self) -> Result<PieceMove, &'static str> {
+    /// 반복 국면이나 수 규칙으로 무승부를 요청할 수 있는지 여부
+    pub fn can_claim_draw(&self) -> bool {
+        self.get_repetition_count() >= self.draw_rules.claim_repetitions
+            || self.halfmove_clock >= self.draw_rules.claim_moves * 2
+    }
+
+    pub fn get_status(&self) -> GameStatus {
+        if !self.has_legal_move() {
+            return if self.is_check() {
+                GameStatus::Checkmate(!self.turn)
+            } else {
+                GameStatus::Stalemate
+            };
+        }
+
+        match self.get_automatic_draw() {
+            Some(reason) => GameStatus::Draw(reason),
+            None => GameStatus::Ongoing,
+        }
+    }
+
+    pub fn is_checkmate(&self) -> bool {
+        matches!(self.get_status(), GameStatus::Checkmate(_))
+    }
+
+    pub fn is_stalemate(&self) -> bool {
+        self.get_status() == GameStatus::Stalemate
+    }
+
+    pub fn is_draw(&self) -> bool {
+        matches!(self.get_status(), GameStatus::Draw(_))
+    }
+
+    pub fn is_game_over(&self) -> bool {
+        self.get_status() != GameStatus::Ongoing
+    }
+
+    /// 체크메이트라면 승리한 색
+    pub fn get_winner(&self) -> Option<Color> {
+        match self.get_status() {
+            GameStatus::Checkmate(color) => Some(color),
+            _ => None,
+        }
+    }
+
+    /// 합법적인 수만 둡니다.
+    ///
+    /// 둘 수 있는 수가 있으면 체크메이트나 스테일메이트가 아니므로,
+    /// 수를 검사한 뒤 자동 무승부만 확인해 전체 수 생성을 피합니다.
+    pub fn push_move(&mut self, piece_move: PieceMove) -> Result<(), WarpSquareError> {
+        let color = match piece_move.move_type {
+            MoveType::Move => {
+                for square in [&piece_move.source, &piece_move.destination] {
+                    if !self.board.validate_square(BitBoard::from_square(square))
+                        || self.board.convert_board_type(square.level).is_none()
+                    {
+                        return Err(WarpSquareError::InvalidSquareForLevel);
+                    }
+                }
+
+                match self
+                    .board
+                    .get_piece(BitBoard::from_square(&piece_move.source))
+                {
+                    Some(piece) => piece.color,
+                    None => return Err(WarpSquareError::NoPieceAtSource),
+                }
+            }
+            MoveType::BoardMove => {
+                let board_type = self
+                    .board
+                    .validate_board_move(piece_move.source.level, piece_move.destination.level)?;
+
+                match self.board.get_board_controller(board_type) {
+                    Some(color) => color,
+                    None => return Err(WarpSquareError::BoardOverloaded),
+                }
+            }
+        };
+
+        if color != self.turn {
+            return Err(WarpSquareError::WrongSideToMove);
+        }
+
+        self.validate_promotion(&piece_move)?;
+
+        if !self.pseudo_legal_move(&piece_move) {
+            return Err(WarpSquareError::IllegalDestination);
+        }
+
+        if self.board.leaves_king_in_check(&piece_move, self.turn) {
+            return Err(WarpSquareError::KingInCheck);
+        }
+
+        if self.get_automatic_draw().is_some() {
+            return Err(WarpSquareError::GameOver);
+        }
+
+        self.make_move(piece_move)
+    }
+
+    /// 합법 여부를 검사하지 않고 수를 둡니다.
+    pub(crate) fn make_move(&mut self, piece_move: PieceMove) -> Result<(), WarpSquareError> {
+        let hash = self.get_hash();
+        let undo = self.board.make_move(&piece_move)?;
+
+        let is_pawn_move = match &undo.piece {
+            Some(piece) => piece.piece_type == PieceType::Pawn,
+            None => false,
+        };
+        let is_capture = undo.is_capture;
+
+        self.move_stack.push(MoveRecord {
+            piece_move,
+            undo,
+            halfmove_clock: self.halfmove_clock,
+            hash,
+        });
+
+        if is_pawn_move || is_capture {
+            self.halfmove_clock = 0;
+        } else {
+            self.halfmove_clock += 1;
+        }
+
+        if self.turn == Color::Black {
+            self.fullmove_number += 1;
+        }
+
         self.pass_turn();
+
+        Ok(())
+    }
+
+    pub fn pop_move(&mut self) -> Result<PieceMove, WarpSquareError> {
         match self.move_stack.pop() {
-            Some((piece_move, snapshot)) => {
-                snapshot.restore(&mut self.board);
-                Ok(piece_move)
+            Some(record) => {
+                self.board.unmake_move(&record.piece_move, record.undo);
+
+                self.pass_turn();
+                self.halfmove_clock = record.halfmove_clock;
+
+                if self.turn == Color::Black {
+                    self.fullmove_number -= 1;
+                }
+
+                Ok(record.piece_move)
             }
-            None => Err("Nothing to pop"),
+            None => Err(WarpSquareError::NothingToUndo),
         }
     }
 
+    /// depth 수 뒤에 나올 수 있는 국면의 수
+    pub fn perft(&mut self, depth: u32) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+
+        let moves = self.legal_moves();
+
+        if depth == 1 {
+            return moves.len() as u64;
+        }
+
+        let mut nodes = 0;
+
+        for piece_move in moves {
+            if self.make_move(piece_move).is_ok() {
+                nodes += self.perft(depth - 1);
+                let _ = self.pop_move();
+            }
+        }
+
+        nodes
+    }
+
+    /// 현재 국면의 합법적인 수마다 perft(depth - 1) 결과
+    pub fn perft_divide(&mut self, depth: u32) -> Vec<(PieceMove, u64)> {
+        let mut result = Vec::new();
+
+        if depth == 0 {
+            return result;
+        }
+
+        for piece_move in self.legal_moves() {
+            if self.make_move(piece_move.clone()).is_ok() {
+                result.push((piece_move, self.perft(depth - 1)));
+                let _ = self.pop_move();
+            }
+        }
+
+        result
+    }
+
     pub fn print(&self) {
         println!("White Board: ");
         for bit_square in BitBoard::WHITE_SET.iter() {
@@ -237,3 +742,80 @@ impl Game {
         }
     }
 }
+
+impl Default for Game {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn perft_start_position() {
+        let mut game = Game::new();
+
+        assert_eq!(game.perft(1), 21);
+        assert_eq!(game.perft(2), 441);
+        assert_eq!(game.perft(3), 10172);
+    }
+
+    #[test]
+    #[ignore = "디버그 빌드에서 느림"]
+    fn perft_start_position_depth_4() {
+        let mut game = Game::new();
+
+        assert_eq!(game.perft(4), 234168);
+    }
+
+    #[test]
+    fn perft_divide_matches_perft() {
+        let mut game = Game::new();
+        let divide = game.perft_divide(3);
+
+        assert_eq!(divide.len(), 21);
+        assert_eq!(divide.iter().map(|(_, nodes)| nodes).sum::<u64>(), 10172);
+    }
+
+    #[test]
+    fn push_pop_restores_position() {
+        let mut game = Game::new();
+        let hash = game.get_hash();
+        let status = game.get_status();
+        let mut random = 0x9E37_79B9_7F4A_7C15u64;
+        let mut pushed = 0;
+
+        for _ in 0..80 {
+            let moves = game.legal_moves();
+
+            if moves.is_empty() || game.get_automatic_draw().is_some() {
+                break;
+            }
+
+            random ^= random << 13;
+            random ^= random >> 7;
+            random ^= random << 17;
+
+            let piece_move = moves[(random % moves.len() as u64) as usize].clone();
+
+            game.push_move(piece_move).unwrap();
+            pushed += 1;
+
+            assert_eq!(game.board.hash, game.board.compute_hash());
+        }
+
+        assert!(pushed > 20);
+
+        for _ in 0..pushed {
+            game.pop_move().unwrap();
+
+            assert_eq!(game.board.hash, game.board.compute_hash());
+        }
+
+        assert_eq!(game.get_hash(), hash);
+        assert_eq!(game.get_status(), status);
+        assert!(game.pop_move().is_err());
+    }
+}
@@ -1,7 +1,17 @@
+pub mod attack_table;
 pub mod bit_board;
 pub mod board;
+pub mod castling;
 pub mod color_mask;
+pub mod error;
+pub mod eval;
 pub mod game;
+pub mod mcts;
+pub mod opening_book;
 pub mod piece;
 pub mod piece_move;
+pub mod search;
 pub mod square;
+pub mod tablebase;
+pub mod transposition_table;
+pub mod zobrist;
@@ -0,0 +1,347 @@
+use std::time::{Duration, Instant};
+
+use crate::{bit_board::BitBoard, game::Game, piece_move::PieceMove, square::Color};
+
+/// 플레이아웃에서 수를 고르는 방법
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum PlayoutPolicy {
+    /// 합법적인 수 중 무작위
+    Random,
+    /// 잡는 수가 있으면 그중에서 무작위
+    CaptureFirst,
+    /// 플레이아웃 없이 평가 함수로 결과를 추정
+    Evaluation,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MctsConfig {
+    pub policy: PlayoutPolicy,
+    /// UCT 탐험 상수, 클수록 덜 둔 수를 자주 시도합니다.
+    pub exploration: f64,
+    /// 수 하나를 고를 때 반복 횟수
+    pub iterations: u32,
+    /// 반복 횟수보다 먼저 닿으면 멈추는 시간
+    pub time: Option<Duration>,
+    /// 플레이아웃 최대 반수, 넘으면 평가 함수로 결과를 추정합니다.
+    pub max_playout_ply: u32,
+    pub seed: u64,
+}
+
+impl MctsConfig {
+    pub fn new() -> Self {
+        Self {
+            policy: PlayoutPolicy::CaptureFirst,
+            exploration: std::f64::consts::SQRT_2,
+            iterations: 1000,
+            time: None,
+            max_playout_ply: 40,
+            seed: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+}
+
+impl Default for MctsConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Node {
+    /// 부모 국면에서 이 노드로 온 수, 루트는 None
+    piece_move: Option<PieceMove>,
+    hash: u64,
+    children: Vec<usize>,
+    /// 아직 자식으로 만들지 않은 수, 처음 방문할 때 채웁니다.
+    untried: Option<Vec<PieceMove>>,
+    visits: u32,
+    /// 이 노드로 수를 둔 쪽이 얻은 보상의 합 (승리 1, 무승부 0.5, 패배 0)
+    reward: f64,
+}
+
+impl Node {
+    fn new(piece_move: Option<PieceMove>, hash: u64) -> Self {
+        Self {
+            piece_move,
+            hash,
+            children: Vec::new(),
+            untried: None,
+            visits: 0,
+            reward: 0.0,
+        }
+    }
+}
+
+/// UCT 몬테카를로 트리 탐색 플레이어
+///
+/// 수를 고른 뒤 다음 차례에 트리를 이어서 쓰므로 같은 게임에 계속 사용하는 것이 좋습니다.
+pub struct Mcts {
+    pub config: MctsConfig,
+    /// 루트가 항상 0번
+    nodes: Vec<Node>,
+    rng: u64,
+}
+
+impl Mcts {
+    pub fn new(config: MctsConfig) -> Self {
+        Self {
+            config,
+            nodes: Vec::new(),
+            rng: config.seed.max(1),
+        }
+    }
+
+    /// 트리를 버립니다.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+
+    /// 루트에서 수를 둔 횟수, 가장 많이 둔 수 순서입니다.
+    pub fn get_root_visits(&self) -> Vec<(PieceMove, u32)> {
+        let mut visits: Vec<_> = match self.nodes.first() {
+            Some(root) => root
+                .children
+                .iter()
+                .filter_map(|child| {
+                    let child = &self.nodes[*child];
+
+                    Some((child.piece_move.clone()?, child.visits))
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        visits.sort_by_key(|(_, visits)| std::cmp::Reverse(*visits));
+
+        visits
+    }
+
+    /// 현재 차례에 둘 수를 고릅니다. 탐색이 끝나면 game은 원래 국면으로 돌아옵니다.
+    pub fn search(&mut self, game: &mut Game) -> Option<PieceMove> {
+        let start = Instant::now();
+
+        self.reuse_tree(game.get_hash());
+
+        for iteration in 0..self.config.iterations {
+            if iteration > 0 && self.config.time.is_some_and(|time| start.elapsed() >= time) {
+                break;
+            }
+
+            self.iterate(game);
+        }
+
+        self.get_root_visits()
+            .into_iter()
+            .next()
+            .map(|(piece_move, _)| piece_move)
+    }
+
+    /// 이전 탐색의 루트에서 두 수 안에 hash 국면이 있으면 그 노드를 새 루트로 삼습니다.
+    fn reuse_tree(&mut self, hash: u64) {
+        let new_root = match self.nodes.first() {
+            Some(root) if root.hash == hash => return,
+            Some(root) => root
+                .children
+                .iter()
+                .flat_map(|child| {
+                    std::iter::once(*child).chain(self.nodes[*child].children.iter().copied())
+                })
+                .find(|index| self.nodes[*index].hash == hash),
+            None => None,
+        };
+
+        let old_nodes = std::mem::take(&mut self.nodes);
+
+        match new_root {
+            Some(new_root) => self.copy_subtree(old_nodes, new_root),
+            None => self.nodes.push(Node::new(None, hash)),
+        }
+
+        self.nodes[0].piece_move = None;
+    }
+
+    /// old_nodes의 root 아래 노드만 앞에서부터 다시 채웁니다.
+    fn copy_subtree(&mut self, mut old_nodes: Vec<Node>, root: usize) {
+        let mut stack = vec![(root, None)];
+
+        while let Some((old_index, parent)) = stack.pop() {
+            let mut node = std::mem::replace(&mut old_nodes[old_index], Node::new(None, 0));
+            let index = self.nodes.len();
+
+            for child in std::mem::take(&mut node.children) {
+                stack.push((child, Some(index)));
+            }
+
+            self.nodes.push(node);
+
+            if let Some(parent) = parent {
+                self.nodes[parent].children.push(index);
+            }
+        }
+    }
+
+    fn next_random(&mut self) -> u64 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+
+        self.rng
+    }
+
+    /// 선택, 확장, 플레이아웃, 역전파를 한 번 합니다.
+    fn iterate(&mut self, game: &mut Game) {
+        let root_turn = game.turn;
+        let mut path = vec![0];
+        let mut node = 0;
+
+        loop {
+            if self.nodes[node].untried.is_none() {
+                let moves = match game.get_automatic_draw() {
+                    Some(_) => Vec::new(),
+                    None => game.legal_moves(),
+                };
+
+                self.nodes[node].untried = Some(moves);
+            }
+
+            let untried = self.nodes[node].untried.as_ref().map_or(0, Vec::len);
+
+            if untried > 0 {
+                let choice = (self.next_random() % untried as u64) as usize;
+                let piece_move = match self.nodes[node].untried.as_mut() {
+                    Some(moves) => moves.swap_remove(choice),
+                    None => break,
+                };
+
+                if game.make_move(piece_move.clone()).is_err() {
+                    continue;
+                }
+
+                let child = self.nodes.len();
+
+                self.nodes
+                    .push(Node::new(Some(piece_move), game.get_hash()));
+                self.nodes[node].children.push(child);
+                path.push(child);
+
+                break;
+            }
+
+            node = match self.select_child(node) {
+                Some(child) => child,
+                None => break,
+            };
+
+            let piece_move = self.nodes[node].piece_move.clone();
+
+            match piece_move.map(|piece_move| game.make_move(piece_move)) {
+                Some(Ok(())) => path.push(node),
+                _ => break,
+            }
+        }
+
+        let white_reward = self.playout(game);
+
+        for _ in 1..path.len() {
+            let _ = game.pop_move();
+        }
+
+        for (depth, node) in path.into_iter().enumerate() {
+            // 깊이가 홀수인 노드는 루트 차례의 색이 둔 수
+            let mover = match depth % 2 {
+                1 => root_turn,
+                _ => !root_turn,
+            };
+
+            let node = &mut self.nodes[node];
+            node.visits += 1;
+            node.reward += match mover {
+                Color::White => white_reward,
+                Color::Black => 1.0 - white_reward,
+            };
+        }
+    }
+
+    /// 평균 보상과 탐험 항의 합이 가장 큰 자식
+    fn select_child(&self, node: usize) -> Option<usize> {
+        let log_visits = (self.nodes[node].visits.max(1) as f64).ln();
+
+        self.nodes[node].children.iter().copied().max_by(|a, b| {
+            let uct = |index: usize| {
+                let child = &self.nodes[index];
+                let visits = child.visits.max(1) as f64;
+
+                child.reward / visits + self.config.exploration * (log_visits / visits).sqrt()
+            };
+
+            uct(*a).total_cmp(&uct(*b))
+        })
+    }
+
+    /// 게임이 끝나거나 최대 반수에 닿을 때까지 두고 백이 얻은 보상을 반환합니다.
+    fn playout(&mut self, game: &mut Game) -> f64 {
+        let mut ply = 0;
+        let mut moves = Vec::new();
+
+        let reward = loop {
+            if game.get_automatic_draw().is_some() {
+                break 0.5;
+            }
+
+            game.fill_legal_moves(&mut moves);
+
+            if moves.is_empty() {
+                break match (game.is_check(), game.turn) {
+                    (true, Color::White) => 0.0,
+                    (true, Color::Black) => 1.0,
+                    (false, _) => 0.5,
+                };
+            }
+
+            if ply >= self.config.max_playout_ply || self.config.policy == PlayoutPolicy::Evaluation
+            {
+                break estimate_reward(game);
+            }
+
+            if self.config.policy == PlayoutPolicy::CaptureFirst {
+                let captures: Vec<_> = moves
+                    .iter()
+                    .filter(|piece_move| is_capture(game, piece_move))
+                    .cloned()
+                    .collect();
+
+                if !captures.is_empty() {
+                    moves = captures;
+                }
+            }
+
+            let choice = (self.next_random() % moves.len() as u64) as usize;
+
+            if game.make_move(moves.swap_remove(choice)).is_err() {
+                break estimate_reward(game);
+            }
+
+            ply += 1;
+        };
+
+        for _ in 0..ply {
+            let _ = game.pop_move();
+        }
+
+        reward
+    }
+}
+
+fn is_capture(game: &Game, piece_move: &PieceMove) -> bool {
+    !piece_move.is_board_move()
+        && game
+            .board
+            .get_piece(BitBoard::from_square(&piece_move.destination))
+            .is_some_and(|piece| piece.color != game.turn)
+}
+
+/// 평가 점수를 백의 승리 확률처럼 0 ~ 1로 바꿉니다. 400 센티폰 차이가 약 0.73입니다.
+fn estimate_reward(game: &Game) -> f64 {
+    let score = game.evaluate().get_total() as f64;
+
+    1.0 / (1.0 + (-score / 400.0).exp())
+}
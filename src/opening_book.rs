@@ -0,0 +1,238 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Read, Write},
+    path::Path,
+};
+
+use crate::{error::WarpSquareError, game::Game, piece_move::PieceMove};
+
+/// 파일 맨 앞의 식별자와 형식 버전
+const MAGIC: &[u8; 4] = b"WSOB";
+const VERSION: u8 = 1;
+/// 해시 8바이트, 수 4바이트, 가중치 2바이트
+const ENTRY_SIZE: usize = 14;
+
+/// 국면 하나에서 둘 수 있는 수와 그 가중치
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub struct BookEntry {
+    /// 차례를 포함한 Game::get_hash 값
+    pub hash: u64,
+    pub piece_move: PieceMove,
+    pub weight: u16,
+}
+
+/// 국면 해시로 찾는 오프닝 북
+///
+/// 파일 형식은 MAGIC, VERSION, 항목 수(u32) 뒤에 해시 순으로 정렬된 항목이 이어집니다.
+/// 항목은 해시(u64), PieceMove::to_bits(u32), 가중치(u16)이며 모두 리틀 엔디언입니다.
+#[derive(Clone, Default)]
+pub struct OpeningBook {
+    entries: Vec<BookEntry>,
+}
+
+impl OpeningBook {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn get_size(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 해당 국면에 저장된 수, 가중치가 큰 순서입니다.
+    pub fn get_entries(&self, hash: u64) -> &[BookEntry] {
+        let start = self.entries.partition_point(|entry| entry.hash < hash);
+        let end = self.entries.partition_point(|entry| entry.hash <= hash);
+
+        &self.entries[start..end]
+    }
+
+    /// 가중치에 비례하는 확률로 수를 고릅니다. random이 같으면 같은 수를 고릅니다.
+    pub fn choose_move(&self, hash: u64, random: u64) -> Option<&PieceMove> {
+        let entries = self.get_entries(hash);
+        let total: u64 = entries.iter().map(|entry| entry.weight as u64).sum();
+
+        if total == 0 {
+            return None;
+        }
+
+        let mut target = random % total;
+
+        for entry in entries {
+            if target < entry.weight as u64 {
+                return Some(&entry.piece_move);
+            }
+
+            target -= entry.weight as u64;
+        }
+
+        None
+    }
+
+    pub fn read(reader: &mut impl Read) -> io::Result<Self> {
+        let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message);
+
+        let mut header = [0; 9];
+        reader.read_exact(&mut header)?;
+
+        if &header[..4] != MAGIC {
+            return Err(invalid("Not an opening book"));
+        }
+
+        if header[4] != VERSION {
+            return Err(invalid("Unsupported opening book version"));
+        }
+
+        // 항목 수는 파일에서 읽은 값이므로 미리 할당하지 않고, 파일이 짧으면 read_exact가 실패합니다.
+        let count = u32::from_le_bytes([header[5], header[6], header[7], header[8]]) as usize;
+        let mut entries = Vec::new();
+        let mut buffer = [0; ENTRY_SIZE];
+
+        for _ in 0..count {
+            reader.read_exact(&mut buffer)?;
+
+            let (hash, rest) = buffer.split_at(8);
+            let (bits, weight) = rest.split_at(4);
+
+            entries.push(BookEntry {
+                hash: u64::from_le_bytes(hash.try_into().unwrap()),
+                piece_move: PieceMove::from_bits(u32::from_le_bytes(bits.try_into().unwrap()))
+                    .ok_or_else(|| invalid("Empty move in opening book"))?,
+                weight: u16::from_le_bytes(weight.try_into().unwrap()),
+            });
+        }
+
+        if entries.windows(2).any(|pair| pair[0].hash > pair[1].hash) {
+            return Err(invalid("Opening book entries are not sorted"));
+        }
+
+        Ok(Self { entries })
+    }
+
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        writer.write_all(&(self.entries.len() as u32).to_le_bytes())?;
+
+        for entry in self.entries.iter() {
+            writer.write_all(&entry.hash.to_le_bytes())?;
+            writer.write_all(&entry.piece_move.to_bits().to_le_bytes())?;
+            writer.write_all(&entry.weight.to_le_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::read(&mut io::BufReader::new(fs::File::open(path)?))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = io::BufWriter::new(fs::File::create(path)?);
+
+        self.write(&mut writer)?;
+        writer.flush()
+    }
+}
+
+/// 기보를 모아 OpeningBook을 만듭니다.
+pub struct OpeningBookBuilder {
+    /// 기보마다 앞에서부터 넣을 최대 수 (반수 기준)
+    pub max_ply: usize,
+    counts: HashMap<(u64, u32), u32>,
+}
+
+impl OpeningBookBuilder {
+    pub fn new(max_ply: usize) -> Self {
+        Self {
+            max_ply,
+            counts: HashMap::new(),
+        }
+    }
+
+    /// 처음 국면부터 기보를 두면서 각 국면에서 둔 수의 가중치를 1씩 올립니다.
+    ///
+    /// 둘 수 없는 수가 나오면 그 오류를 반환하고, 그 앞까지의 수는 남겨 둡니다.
+    pub fn add_game(&mut self, moves: &[PieceMove]) -> Result<(), WarpSquareError> {
+        let mut game = Game::new();
+
+        for piece_move in moves.iter().take(self.max_ply) {
+            let hash = game.get_hash();
+
+            game.push_move(piece_move.clone())?;
+
+            *self.counts.entry((hash, piece_move.to_bits())).or_default() += 1;
+        }
+
+        Ok(())
+    }
+
+    /// 공백으로 구분한 수 표기 기보를 추가합니다. (예: "c2W-c3W a7B-a5B")
+    pub fn add_notation(&mut self, notation: &str) -> Result<(), WarpSquareError> {
+        let moves = notation
+            .split_whitespace()
+            .map(|piece_move| piece_move.parse())
+            .collect::<Result<Vec<PieceMove>, _>>()?;
+
+        self.add_game(&moves)
+    }
+
+    pub fn build(&self) -> OpeningBook {
+        let mut entries: Vec<BookEntry> = self
+            .counts
+            .iter()
+            .filter_map(|((hash, bits), count)| {
+                Some(BookEntry {
+                    hash: *hash,
+                    piece_move: PieceMove::from_bits(*bits)?,
+                    weight: (*count).min(u16::MAX as u32) as u16,
+                })
+            })
+            .collect();
+
+        entries.sort_by(|a, b| {
+            a.hash
+                .cmp(&b.hash)
+                .then(b.weight.cmp(&a.weight))
+                .then(a.piece_move.to_bits().cmp(&b.piece_move.to_bits()))
+        });
+
+        OpeningBook { entries }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_read_round_trip() {
+        let mut builder = OpeningBookBuilder::new(4);
+
+        builder.add_notation("c2W-c3W a7B-a5B d1W-c3N").unwrap();
+        builder.add_notation("c2W-c3W c7B-c6B").unwrap();
+
+        let book = builder.build();
+        let mut buffer = Vec::new();
+
+        book.write(&mut buffer).unwrap();
+
+        let read = OpeningBook::read(&mut buffer.as_slice()).unwrap();
+
+        assert_eq!(read.get_size(), 4);
+        assert_eq!(read.entries, book.entries);
+    }
+
+    #[test]
+    fn read_rejects_truncated_entries() {
+        let mut buffer = MAGIC.to_vec();
+
+        buffer.push(VERSION);
+        buffer.extend_from_slice(&u32::MAX.to_le_bytes());
+
+        assert!(OpeningBook::read(&mut buffer.as_slice()).is_err());
+    }
+}
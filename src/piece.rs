@@ -1,4 +1,5 @@
 use crate::{
+    attack_table,
     bit_board::{BitBoard, BitBoardSet, BoardType},
     board::Board,
     square::{Color, Square},
@@ -16,7 +17,28 @@ pub enum PieceType {
 
 pub const NUM_PIECES: usize = 6;
 
+/// 폰이 승격할 수 있는 기물
+pub const PROMOTION_PIECES: [PieceType; 4] = [
+    PieceType::Queen,
+    PieceType::Rook,
+    PieceType::Bishop,
+    PieceType::Knight,
+];
+
 impl PieceType {
+    /// 대소문자 구분 없이 기물 문자를 변환합니다.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            'P' => Some(Self::Pawn),
+            'N' => Some(Self::Knight),
+            'B' => Some(Self::Bishop),
+            'R' => Some(Self::Rook),
+            'Q' => Some(Self::Queen),
+            'K' => Some(Self::King),
+            _ => None,
+        }
+    }
+
     pub fn get_char(&self, color: Color) -> &'static str {
         match color {
             Color::White => match self {
@@ -39,12 +61,11 @@ impl PieceType {
     }
 }
 
-#[derive(Clone, Eq, PartialEq, PartialOrd, Debug, Hash)]
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug, Hash)]
 pub struct Piece {
     pub piece_type: PieceType,
     pub color: Color,
     pub position: BitBoard,
-    pub attacks: BitBoardSet,
     pub is_moved: bool,
 }
 
@@ -54,7 +75,6 @@ impl Piece {
             piece_type,
             color,
             position,
-            attacks: BitBoardSet::new(),
             is_moved: false,
         }
     }
@@ -69,9 +89,10 @@ impl Piece {
 
     pub fn get_attack_squares(&self, board: &Board) -> Vec<Square> {
         let mut result = Vec::new();
+        let attacks = self.compute_attacks(board);
 
         for board_type in BoardType::iter() {
-            for bit_square in self.attacks[board_type].iter() {
+            for bit_square in attacks[board_type].iter() {
                 result.push(
                     (bit_square | board.convert_level(board_type).into_bit_board()).into_square(),
                 );
@@ -81,6 +102,7 @@ impl Piece {
         result
     }
 
+    /// 광선을 막는 Square, 그 Square의 모든 보드가 기물로 차 있거나 비어 있는 경우입니다.
     pub fn compute_ray_occupied(board: &Board) -> BitBoard {
         let occupied = (board.occupied_piece.union() | &board.occupied_void).intersection();
 
@@ -94,53 +116,44 @@ impl Piece {
         occupied & board_area
     }
 
-    pub fn update_attacks(&mut self, board: &Board) {
-        self.attacks = match self.piece_type {
+    /// 현재 보드에서 기물이 움직일 수 있는 Square를 보드별로 계산합니다.
+    pub fn compute_attacks(&self, board: &Board) -> BitBoardSet {
+        match self.piece_type {
             PieceType::Pawn => self.compute_pawn_attacks(board),
             PieceType::Knight => self.compute_knight_attacks(board),
             PieceType::Bishop => self.compute_bishop_attacks(board),
             PieceType::Rook => self.compute_rook_attacks(board),
             PieceType::Queen => self.compute_queen_attacks(board),
             PieceType::King => self.compute_king_attacks(board),
-        };
+        }
     }
 
     pub fn compute_pawn_attacks(&self, board: &Board) -> BitBoardSet {
         let position = self.position.remove_level();
         let occupied = (board.occupied_piece.union() | &board.occupied_void).intersection();
 
-        let mut attacks = BitBoardSet::new();
-
         // 이동 행마
-        {
-            let mut destination = position.forward(self.color);
-
-            if !self.is_moved && !occupied.contains(destination) {
-                destination |= destination.forward(self.color);
-            }
-
-            let empty_boards = board.get_empty_board(destination, None);
+        let mut destination = attack_table::get_pawn_pushes(position, self.color);
 
-            for (board_type, square, is_empty) in &empty_boards {
-                if *is_empty {
-                    attacks[*board_type] |= *square;
-                }
-            }
+        if !self.is_moved && !destination.is_empty() && !occupied.contains(destination) {
+            destination |= attack_table::get_pawn_pushes(destination, self.color);
         }
 
+        let (mut attacks, _) = board.split_empty_squares(destination, None);
+
         // 공격 행마
-        {
-            let destination = position.forward_left(self.color) | position.forward_right(self.color);
+        let destination = attack_table::get_pawn_attacks(position, self.color);
+        let (_, captures) = board.split_empty_squares(destination, Some(self.color));
 
-            let empty_boards = board.get_empty_board(destination, Some(self.color));
+        attacks = attacks | captures;
 
-            for (board_type, square, is_empty) in &empty_boards {
-                if !*is_empty {
-                    attacks[*board_type] |= *square;
-                }
+        // 앙파상, 건너뛴 Square가 있는 모든 보드로 잡을 수 있음
+        if let Some(en_passant) = board.get_en_passant(self.color) {
+            if destination.contains(en_passant.square) {
+                let (en_passant_squares, _) = board.split_empty_squares(en_passant.square, None);
+
+                attacks = attacks | en_passant_squares;
             }
-            
-            // TODO: 앙파상 추가
         }
 
         attacks
@@ -149,110 +162,39 @@ impl Piece {
     pub fn compute_knight_attacks(&self, board: &Board) -> BitBoardSet {
         let position = self.position.remove_level();
 
-        let mut attacks = BitBoardSet::new();
-        let mut destination = BitBoard::EMPTY;
-
-        destination |=
-            BitBoard::from_bits_retain(position.bits() >> 21 & (!BitBoard::NINE_RANKS).bits());
-        destination |=
-            BitBoard::from_bits_retain(position.bits() >> 19 & (!BitBoard::ZERO_RANKS).bits());
-        destination |= BitBoard::from_bits_retain(
-            position.bits() >> 12 & (!(BitBoard::NINE_RANKS | BitBoard::EIGHT_RANKS)).bits(),
-        );
-        destination |= BitBoard::from_bits_retain(
-            position.bits() >> 8 & (!(BitBoard::ZERO_RANKS | BitBoard::ONE_RANKS)).bits(),
-        );
-        destination |= BitBoard::from_bits_retain(
-            position.bits() << 8 & (!(BitBoard::NINE_RANKS | BitBoard::EIGHT_RANKS)).bits(),
-        );
-        destination |= BitBoard::from_bits_retain(
-            position.bits() << 12 & (!(BitBoard::ZERO_RANKS | BitBoard::ONE_RANKS)).bits(),
-        );
-        destination |=
-            BitBoard::from_bits_retain(position.bits() << 19 & (!BitBoard::NINE_RANKS).bits());
-        destination |=
-            BitBoard::from_bits_retain(position.bits() << 21 & (!BitBoard::ZERO_RANKS).bits());
-
-        let empty_boards = board.get_empty_board(destination, Some(!self.color));
-
-        for (board_type, square, is_empty) in &empty_boards {
-            if *is_empty {
-                attacks[*board_type] |= *square;
-            }
-        }
+        let destination = attack_table::get_knight_attacks(position);
+
+        let (attacks, _) = board.split_empty_squares(destination, Some(!self.color));
 
         attacks
     }
 
     pub fn compute_bishop_attacks(&self, board: &Board) -> BitBoardSet {
         let position = self.position.remove_level();
-        let occupied = Self::compute_ray_occupied(board);
 
-        let mut attacks = BitBoardSet::new();
-        let mut destination = BitBoard::EMPTY;
+        let destination = attack_table::get_bishop_attacks(position, board.ray_occupied);
 
-        destination |= position.ray(occupied, |current| current.down_left());
-        destination |= position.ray(occupied, |current| current.down_right());
-        destination |= position.ray(occupied, |current| current.up_left());
-        destination |= position.ray(occupied, |current| current.up_right());
-
-        let empty_boards = board.get_empty_board(destination, Some(!self.color));
-
-        for (board_type, square, is_empty) in &empty_boards {
-            if *is_empty {
-                attacks[*board_type] |= *square;
-            }
-        }
+        let (attacks, _) = board.split_empty_squares(destination, Some(!self.color));
 
         attacks
     }
 
     pub fn compute_rook_attacks(&self, board: &Board) -> BitBoardSet {
         let position = self.position.remove_level();
-        let occupied = Self::compute_ray_occupied(board);
 
-        let mut attacks = BitBoardSet::new();
-        let mut destination = BitBoard::EMPTY;
+        let destination = attack_table::get_rook_attacks(position, board.ray_occupied);
 
-        destination |= position.ray(occupied, |current| current.down());
-        destination |= position.ray(occupied, |current| current.up());
-        destination |= position.ray(occupied, |current| current.left());
-        destination |= position.ray(occupied, |current| current.right());
-
-        let empty_boards = board.get_empty_board(destination, Some(!self.color));
-
-        for (board_type, square, is_empty) in &empty_boards {
-            if *is_empty {
-                attacks[*board_type] |= *square;
-            }
-        }
+        let (attacks, _) = board.split_empty_squares(destination, Some(!self.color));
 
         attacks
     }
 
     pub fn compute_queen_attacks(&self, board: &Board) -> BitBoardSet {
         let position = self.position.remove_level();
-        let occupied = Self::compute_ray_occupied(board);
 
-        let mut attacks = BitBoardSet::new();
-        let mut destination = BitBoard::EMPTY;
+        let destination = attack_table::get_queen_attacks(position, board.ray_occupied);
 
-        destination |= position.ray(occupied, |current| current.down());
-        destination |= position.ray(occupied, |current| current.up());
-        destination |= position.ray(occupied, |current| current.left());
-        destination |= position.ray(occupied, |current| current.right());
-        destination |= position.ray(occupied, |current| current.down_left());
-        destination |= position.ray(occupied, |current| current.down_right());
-        destination |= position.ray(occupied, |current| current.up_left());
-        destination |= position.ray(occupied, |current| current.up_right());
-
-        let empty_boards = board.get_empty_board(destination, Some(!self.color));
-
-        for (board_type, square, is_empty) in &empty_boards {
-            if *is_empty {
-                attacks[*board_type] |= *square;
-            }
-        }
+        let (attacks, _) = board.split_empty_squares(destination, Some(!self.color));
 
         attacks
     }
@@ -260,25 +202,9 @@ impl Piece {
     pub fn compute_king_attacks(&self, board: &Board) -> BitBoardSet {
         let position = self.position.remove_level();
 
-        let mut attacks = BitBoardSet::new();
-        let mut destination = BitBoard::EMPTY;
+        let destination = attack_table::get_king_attacks(position);
 
-        destination |= position.down();
-        destination |= position.up();
-        destination |= position.left();
-        destination |= position.right();
-        destination |= position.down_left();
-        destination |= position.down_right();
-        destination |= position.up_left();
-        destination |= position.down_right();
-
-        let empty_boards = board.get_empty_board(destination, Some(!self.color));
-
-        for (board_type, square, is_empty) in &empty_boards {
-            if *is_empty {
-                attacks[*board_type] |= *square;
-            }
-        }
+        let (attacks, _) = board.split_empty_squares(destination, Some(!self.color));
 
         attacks
     }
@@ -1,10 +1,24 @@
-use crate::{square::Square, piece::PieceType};
+use std::{fmt, str::FromStr};
+
+use crate::{
+    bit_board::BitBoard,
+    error::WarpSquareError,
+    piece::PieceType,
+    square::{Color, Level, Square},
+};
+
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug, Hash)]
+pub enum MoveType {
+    Move,
+    BoardMove,
+}
 
 #[derive(Clone, Eq, PartialEq, PartialOrd, Debug, Hash)]
 pub struct PieceMove {
     pub source: Square,
     pub destination: Square,
     pub promotion: Option<PieceType>,
+    pub move_type: MoveType,
 }
 
 impl PieceMove {
@@ -13,6 +27,129 @@ impl PieceMove {
             source,
             destination,
             promotion,
+            move_type: MoveType::Move,
+        }
+    }
+
+    /// 공격 보드 이동
+    ///
+    /// source, destination은 각 핀의 첫 번째 Square로 채워집니다.
+    pub fn new_board_move(source: Level, destination: Level) -> Self {
+        Self {
+            source: (source.get_bit_board() | source.into_bit_board()).into_square(),
+            destination: (destination.get_bit_board() | destination.into_bit_board()).into_square(),
+            promotion: None,
+            move_type: MoveType::BoardMove,
+        }
+    }
+
+    pub fn is_board_move(&self) -> bool {
+        self.move_type == MoveType::BoardMove
+    }
+
+    /// 출발 10비트, 도착 10비트, 승격 3비트, 공격 보드 이동 1비트로 압축합니다. 0이 되지 않습니다.
+    ///
+    /// Square는 Square 번호 6비트와 Level 4비트입니다.
+    pub fn to_bits(&self) -> u32 {
+        let encode_square = |square: &Square| {
+            let square = BitBoard::from_square(square);
+
+            square.get_index() as u32 | (square.get_level() as u32) << 6
+        };
+
+        let promotion = self.promotion.map_or(0, |piece_type| piece_type as u32 + 1);
+
+        encode_square(&self.source)
+            | encode_square(&self.destination) << 10
+            | promotion << 20
+            | (self.is_board_move() as u32) << 23
+    }
+
+    /// to_bits로 압축한 수를 되돌립니다. 0이면 None입니다.
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        if bits == 0 {
+            return None;
+        }
+
+        let decode_square = |bits: u32| {
+            BitBoard::from_bits_retain(
+                1 << (bits & 0x3F) | ((bits >> 6) as u64 & 0xF) << BitBoard::LEVEL_SHIFT,
+            )
+            .into_square()
+        };
+
+        let promotion = match (bits >> 20) & 0x7 {
+            0 => None,
+            1 => Some(PieceType::Pawn),
+            2 => Some(PieceType::Knight),
+            3 => Some(PieceType::Bishop),
+            4 => Some(PieceType::Rook),
+            5 => Some(PieceType::Queen),
+            _ => Some(PieceType::King),
+        };
+
+        Some(Self {
+            source: decode_square(bits & 0x3FF),
+            destination: decode_square((bits >> 10) & 0x3FF),
+            promotion,
+            move_type: match (bits >> 23) & 1 {
+                0 => MoveType::Move,
+                _ => MoveType::BoardMove,
+            },
+        })
+    }
+}
+
+/// 출발, 도착 Square를 -로 잇고 승격 기물을 =로 붙입니다. (예: a2W-a4N, b7B-b8B=Q)
+/// 공격 보드 이동은 핀만 표기합니다. (예: QL1-QL3)
+impl fmt::Display for PieceMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_board_move() {
+            return write!(f, "{}-{}", self.source.level, self.destination.level);
+        }
+
+        write!(f, "{}-{}", self.source, self.destination)?;
+
+        if let Some(promotion) = self.promotion {
+            write!(f, "={}", promotion.get_char(Color::White))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for PieceMove {
+    type Err = WarpSquareError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || WarpSquareError::InvalidNotation(s.to_string());
+
+        let (squares, promotion) = match s.split_once('=') {
+            Some((squares, promotion)) => {
+                let mut chars = promotion.chars();
+
+                match (chars.next().and_then(PieceType::from_char), chars.next()) {
+                    (Some(piece_type), None) => (squares, Some(piece_type)),
+                    _ => return Err(invalid()),
+                }
+            }
+            None => (s, None),
+        };
+
+        let (source, destination) = squares.split_once('-').ok_or_else(invalid)?;
+
+        // 핀만 표기하면 공격 보드 이동
+        if let (Ok(source), Ok(destination)) = (source.parse(), destination.parse()) {
+            return match promotion {
+                Some(_) => Err(invalid()),
+                None => Ok(Self::new_board_move(source, destination)),
+            };
         }
+
+        Ok(Self::new(
+            source.parse().map_err(|_| invalid())?,
+            destination.parse().map_err(|_| invalid())?,
+            promotion,
+        ))
     }
 }
@@ -0,0 +1,461 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    bit_board::BitBoard,
+    game::Game,
+    piece::PieceType,
+    piece_move::PieceMove,
+    tablebase::Wdl,
+    transposition_table::{Bound, TableEntry, TranspositionTable},
+};
+
+/// 체크메이트 점수, 메이트까지 걸리는 수만큼 줄어듭니다.
+pub const MATE_SCORE: i32 = 100_000;
+pub const MAX_DEPTH: u32 = 64;
+
+const INFINITY: i32 = MATE_SCORE + 1;
+/// 테이블베이스가 이긴다고 본 국면의 점수, 메이트까지의 거리만큼 줄어듭니다.
+const TABLEBASE_WIN_SCORE: i32 = MATE_SCORE / 2;
+/// 시간 한도를 확인하는 노드 간격
+const CHECK_INTERVAL: u64 = 1024;
+/// search 함수가 만드는 치환표 크기 (MB)
+pub const DEFAULT_TABLE_SIZE_MB: usize = 16;
+
+/// 탐색 한도, 깊이와 시간 중 먼저 닿는 쪽에서 멈춥니다.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct SearchLimits {
+    pub depth: u32,
+    pub time: Option<Duration>,
+}
+
+impl SearchLimits {
+    pub fn new(depth: u32, time: Option<Duration>) -> Self {
+        Self { depth, time }
+    }
+
+    pub fn depth(depth: u32) -> Self {
+        Self::new(depth, None)
+    }
+
+    pub fn time(time: Duration) -> Self {
+        Self::new(MAX_DEPTH, Some(time))
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub struct SearchResult {
+    pub best_move: Option<PieceMove>,
+    /// 탐색을 시작한 쪽에서 본 점수
+    pub score: i32,
+    /// 끝까지 탐색한 깊이
+    pub depth: u32,
+    pub nodes: u64,
+    pub principal_variation: Vec<PieceMove>,
+}
+
+/// 반복 심화 negamax 알파-베타 탐색
+pub struct Search {
+    limits: SearchLimits,
+    table: Arc<TranspositionTable>,
+    start: Instant,
+    nodes: u64,
+    stopped: bool,
+    /// 병렬 탐색에서 주 스레드가 끝나면 보조 스레드를 멈추는 신호
+    stop_signal: Arc<AtomicBool>,
+}
+
+impl Search {
+    /// 같은 table을 넘기면 여러 탐색이 결과를 함께 씁니다.
+    pub fn new(limits: SearchLimits, table: Arc<TranspositionTable>) -> Self {
+        Self {
+            limits,
+            table,
+            start: Instant::now(),
+            nodes: 0,
+            stopped: false,
+            stop_signal: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// 현재 차례의 최선의 수를 찾습니다. 탐색이 끝나면 game은 원래 국면으로 돌아옵니다.
+    pub fn run(&mut self, game: &mut Game) -> SearchResult {
+        self.start = Instant::now();
+        self.stop_signal.store(false, Ordering::Relaxed);
+
+        self.iterate(game, 1)
+    }
+
+    /// Lazy SMP 병렬 탐색
+    ///
+    /// 보조 스레드는 game의 복사본으로 같은 치환표를 채우고, 결과는 주 스레드의 탐색 결과입니다.
+    /// threads가 1 이하이면 run과 같고 결과가 항상 같습니다.
+    pub fn run_parallel(&mut self, game: &Game, threads: usize) -> SearchResult {
+        self.start = Instant::now();
+        self.stop_signal.store(false, Ordering::Relaxed);
+
+        thread::scope(|scope| {
+            let helpers: Vec<_> = (1..threads)
+                .map(|index| {
+                    let mut helper = Search {
+                        limits: self.limits,
+                        table: self.table.clone(),
+                        start: self.start,
+                        nodes: 0,
+                        stopped: false,
+                        stop_signal: self.stop_signal.clone(),
+                    };
+                    let mut game = game.clone();
+
+                    // 절반은 한 단계 깊게 시작해서 스레드마다 다른 국면을 먼저 채움
+                    scope.spawn(move || helper.iterate(&mut game, 1 + index as u32 % 2).nodes)
+                })
+                .collect();
+
+            let mut result = self.iterate(&mut game.clone(), 1);
+
+            self.stop_signal.store(true, Ordering::Relaxed);
+
+            for helper in helpers {
+                result.nodes += helper.join().unwrap_or_default();
+            }
+
+            result
+        })
+    }
+
+    /// first_depth부터 반복 심화합니다.
+    fn iterate(&mut self, game: &mut Game, first_depth: u32) -> SearchResult {
+        self.nodes = 0;
+        self.stopped = false;
+
+        let mut result = SearchResult {
+            best_move: None,
+            score: 0,
+            depth: 0,
+            nodes: 0,
+            principal_variation: Vec::new(),
+        };
+
+        for depth in first_depth..=self.limits.depth.clamp(1, MAX_DEPTH) {
+            let mut principal_variation = Vec::new();
+            let score = self.negamax(
+                game,
+                depth,
+                0,
+                -INFINITY,
+                INFINITY,
+                &result.principal_variation.clone(),
+                &mut principal_variation,
+            );
+
+            // 중간에 멈춘 반복의 결과는 믿을 수 없음
+            if self.stopped {
+                break;
+            }
+
+            result.best_move = principal_variation.first().cloned();
+            result.score = score;
+            result.depth = depth;
+            result.principal_variation = principal_variation;
+
+            if score.abs() >= MATE_SCORE - MAX_DEPTH as i32 {
+                break;
+            }
+        }
+
+        if result.best_move.is_none() {
+            result.best_move = game.legal_moves().into_iter().next();
+        }
+
+        result.nodes = self.nodes;
+
+        result
+    }
+
+    fn should_stop(&mut self) -> bool {
+        if !self.stopped && self.nodes.is_multiple_of(CHECK_INTERVAL) {
+            self.stopped = self.stop_signal.load(Ordering::Relaxed)
+                || self
+                    .limits
+                    .time
+                    .is_some_and(|time| self.start.elapsed() >= time);
+        }
+
+        self.stopped
+    }
+
+    /// previous는 이전 반복의 주요 변화 중 이 노드 이후 부분입니다.
+    #[allow(clippy::too_many_arguments)]
+    fn negamax(
+        &mut self,
+        game: &mut Game,
+        depth: u32,
+        ply: u32,
+        mut alpha: i32,
+        beta: i32,
+        previous: &[PieceMove],
+        principal_variation: &mut Vec<PieceMove>,
+    ) -> i32 {
+        principal_variation.clear();
+
+        if self.should_stop() {
+            return 0;
+        }
+
+        self.nodes += 1;
+
+        if ply > 0 && is_draw(game) {
+            return 0;
+        }
+
+        if depth == 0 || ply >= MAX_DEPTH {
+            if let Some(score) = get_tablebase_score(game) {
+                return score;
+            }
+
+            return self.quiescence(game, ply, alpha, beta);
+        }
+
+        let hash = game.get_hash();
+        let entry = self.table.probe(hash);
+
+        if let Some(entry) = entry
+            .as_ref()
+            .filter(|entry| ply > 0 && entry.depth >= depth)
+        {
+            let score = score_from_table(entry.score, ply);
+
+            match entry.bound {
+                Bound::Exact => return score,
+                Bound::Lower if score >= beta => return beta,
+                Bound::Upper if score <= alpha => return alpha,
+                _ => (),
+            }
+        }
+
+        let mut moves = game.legal_moves();
+
+        if moves.is_empty() {
+            return match game.is_check() {
+                true => -MATE_SCORE + ply as i32,
+                false => 0,
+            };
+        }
+
+        let table_move = entry.and_then(|entry| entry.best_move);
+
+        order_moves(game, &mut moves, previous.first().or(table_move.as_ref()));
+
+        let original_alpha = alpha;
+        let mut child_variation = Vec::new();
+
+        for piece_move in moves {
+            let child_previous = match previous.split_first() {
+                Some((first, rest)) if *first == piece_move => rest,
+                _ => &[],
+            };
+
+            if game.make_move(piece_move.clone()).is_err() {
+                continue;
+            }
+
+            let score = -self.negamax(
+                game,
+                depth - 1,
+                ply + 1,
+                -beta,
+                -alpha,
+                child_previous,
+                &mut child_variation,
+            );
+
+            let _ = game.pop_move();
+
+            if self.stopped {
+                return 0;
+            }
+
+            if score > alpha {
+                alpha = score;
+
+                principal_variation.clear();
+                principal_variation.push(piece_move);
+                principal_variation.extend_from_slice(&child_variation);
+
+                if alpha >= beta {
+                    break;
+                }
+            }
+        }
+
+        let bound = if alpha >= beta {
+            Bound::Lower
+        } else if alpha > original_alpha {
+            Bound::Exact
+        } else {
+            Bound::Upper
+        };
+
+        self.table.store(
+            hash,
+            &TableEntry {
+                bound,
+                depth,
+                score: score_to_table(alpha, ply),
+                best_move: principal_variation.first().cloned(),
+            },
+        );
+
+        alpha
+    }
+
+    /// 기물을 잡는 수만 이어서 탐색해 수평선 효과를 줄입니다.
+    fn quiescence(&mut self, game: &mut Game, ply: u32, mut alpha: i32, beta: i32) -> i32 {
+        if self.should_stop() {
+            return 0;
+        }
+
+        self.nodes += 1;
+
+        let stand_pat = game.evaluate().get_score(game.turn);
+
+        if stand_pat >= beta || ply >= MAX_DEPTH {
+            return stand_pat;
+        }
+
+        alpha = alpha.max(stand_pat);
+
+        let mut moves = game.legal_moves();
+
+        moves.retain(|piece_move| get_captured_piece(game, piece_move).is_some());
+        order_moves(game, &mut moves, None);
+
+        for piece_move in moves {
+            if game.make_move(piece_move).is_err() {
+                continue;
+            }
+
+            let score = -self.quiescence(game, ply + 1, -beta, -alpha);
+
+            let _ = game.pop_move();
+
+            if self.stopped {
+                return 0;
+            }
+
+            if score >= beta {
+                return score;
+            }
+
+            alpha = alpha.max(score);
+        }
+
+        alpha
+    }
+}
+
+/// limits 안에서 game의 최선의 수를 찾습니다. 호출마다 새 치환표를 씁니다.
+pub fn search(game: &mut Game, limits: SearchLimits) -> SearchResult {
+    let table = Arc::new(TranspositionTable::new(DEFAULT_TABLE_SIZE_MB));
+
+    Search::new(limits, table).run(game)
+}
+
+/// threads개의 스레드로 game의 최선의 수를 찾습니다. game은 바뀌지 않습니다.
+pub fn search_parallel(game: &Game, limits: SearchLimits, threads: usize) -> SearchResult {
+    let table = Arc::new(TranspositionTable::new(DEFAULT_TABLE_SIZE_MB));
+
+    Search::new(limits, table).run_parallel(game, threads)
+}
+
+/// 테이블베이스 결과를 현재 차례 기준의 평가 점수로 바꿉니다.
+///
+/// 테이블베이스는 공격 보드 이동을 빼고 만든 결과이므로 메이트 점수가 아닌 평가 점수로만 씁니다.
+///
+/// 무승부는 공격 보드를 움직여 달라질 수 있으므로 None을 반환해 평가 함수에 맡깁니다.
+fn get_tablebase_score(game: &Game) -> Option<i32> {
+    let entry = game.probe_tablebase()?;
+
+    match entry.wdl {
+        Wdl::Win => Some(TABLEBASE_WIN_SCORE - entry.distance as i32),
+        Wdl::Draw => None,
+        Wdl::Loss => Some(-TABLEBASE_WIN_SCORE + entry.distance as i32),
+    }
+}
+
+/// 메이트 점수를 루트 기준에서 이 노드 기준으로 바꿔 저장합니다.
+fn score_to_table(score: i32, ply: u32) -> i32 {
+    if score >= MATE_SCORE - MAX_DEPTH as i32 {
+        score + ply as i32
+    } else if score <= -MATE_SCORE + MAX_DEPTH as i32 {
+        score - ply as i32
+    } else {
+        score
+    }
+}
+
+fn score_from_table(score: i32, ply: u32) -> i32 {
+    if score >= MATE_SCORE - MAX_DEPTH as i32 {
+        score - ply as i32
+    } else if score <= -MATE_SCORE + MAX_DEPTH as i32 {
+        score + ply as i32
+    } else {
+        score
+    }
+}
+
+/// 탐색 중에는 반복 국면이 한 번만 나와도 무승부로 봅니다.
+fn is_draw(game: &Game) -> bool {
+    game.halfmove_clock >= game.draw_rules.claim_moves * 2
+        || game.get_repetition_count() > 1
+        || game.is_insufficient_material()
+}
+
+fn get_captured_piece(game: &Game, piece_move: &PieceMove) -> Option<PieceType> {
+    if piece_move.is_board_move() {
+        return None;
+    }
+
+    match game
+        .board
+        .get_piece(BitBoard::from_square(&piece_move.destination))
+    {
+        Some(piece) if piece.color != game.turn => Some(piece.piece_type),
+        _ => None,
+    }
+}
+
+/// 이전 반복의 최선의 수, 잡는 수(MVV-LVA), 승격 순서로 정렬합니다.
+fn order_moves(game: &Game, moves: &mut [PieceMove], best_move: Option<&PieceMove>) {
+    let weights = &game.eval_weights;
+
+    moves.sort_by_cached_key(|piece_move| {
+        if Some(piece_move) == best_move {
+            return i32::MIN;
+        }
+
+        let mut score = 0;
+
+        if let Some(victim) = get_captured_piece(game, piece_move) {
+            let attacker = game
+                .board
+                .get_piece(BitBoard::from_square(&piece_move.source))
+                .map_or(0, |piece| weights.get_piece_value(piece.piece_type));
+
+            score += weights.get_piece_value(victim) * 10 - attacker;
+        }
+
+        if let Some(promotion) = piece_move.promotion {
+            score += weights.get_piece_value(promotion);
+        }
+
+        -score
+    });
+}
@@ -1,6 +1,6 @@
-use std::{mem::transmute, ops::Not};
+use std::{fmt, mem::transmute, ops::Not, str::FromStr};
 
-use crate::bit_board::BitBoard;
+use crate::{bit_board::BitBoard, error::WarpSquareError};
 
 #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug, Hash)]
 pub enum Rank {
@@ -30,6 +30,12 @@ impl Rank {
     }
 }
 
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", *self as u8)
+    }
+}
+
 pub const NUM_RANKS: u8 = 10;
 
 #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug, Hash)]
@@ -56,8 +62,39 @@ impl File {
     }
 }
 
+impl fmt::Display for File {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = match self {
+            File::Z => "z",
+            File::A => "a",
+            File::B => "b",
+            File::C => "c",
+            File::D => "d",
+            File::E => "e",
+        };
+
+        write!(f, "{}", file)
+    }
+}
+
+impl File {
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'z' => Some(File::Z),
+            'a' => Some(File::A),
+            'b' => Some(File::B),
+            'c' => Some(File::C),
+            'd' => Some(File::D),
+            'e' => Some(File::E),
+            _ => None,
+        }
+    }
+}
+
 pub const NUM_FILES: u8 = 6;
 
+pub const NUM_SQUARES: usize = NUM_RANKS as usize * NUM_FILES as usize;
+
 #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug, Hash)]
 pub enum Level {
     White = 1,
@@ -107,6 +144,86 @@ impl Level {
             Level::KL6 => BitBoard::KL6_SET,
         }
     }
+
+    /// 공격 보드 핀 번호 (1 ~ 6), 메인 보드는 None
+    pub fn get_pin_number(&self) -> Option<u8> {
+        match self {
+            Level::White | Level::Neutral | Level::Black => None,
+            _ => Some((*self as u8 - Level::QL1 as u8) % 6 + 1),
+        }
+    }
+
+    pub fn is_queen_side(&self) -> bool {
+        (Level::QL1..=Level::QL6).contains(self)
+    }
+
+    pub fn is_king_side(&self) -> bool {
+        (Level::KL1..=Level::KL6).contains(self)
+    }
+
+    /// 공격 보드가 이동할 수 있는 인접 핀
+    ///
+    /// 같은 쪽의 앞뒤, 위아래 핀과 반대쪽의 같은 번호 핀이 인접합니다.
+    pub fn get_adjacent_pins(&self) -> impl Iterator<Item = Level> {
+        let pin_number = self.get_pin_number().unwrap_or(0);
+
+        let (base, opposite) = match self.is_queen_side() {
+            true => (Level::QL1 as u8, Level::KL1 as u8),
+            false => (Level::KL1 as u8, Level::QL1 as u8),
+        };
+
+        let neighbours: &[u8] = match pin_number {
+            0 => &[],
+            1 => &[2, 3],
+            2 => &[1, 3, 4],
+            3 => &[1, 2, 4, 5],
+            4 => &[2, 3, 5, 6],
+            5 => &[3, 4, 6],
+            _ => &[4, 5],
+        };
+
+        neighbours
+            .iter()
+            .map(move |number| Level::from_u8(base + number - 1))
+            .chain((pin_number > 0).then(|| Level::from_u8(opposite + pin_number - 1)))
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::White => write!(f, "W"),
+            Level::Neutral => write!(f, "N"),
+            Level::Black => write!(f, "B"),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = WarpSquareError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let level = match s {
+            "W" => Level::White,
+            "N" => Level::Neutral,
+            "B" => Level::Black,
+            _ => {
+                let (base, number) = match s.split_at_checked(2) {
+                    Some(("QL", number)) => (Level::QL1 as u8, number),
+                    Some(("KL", number)) => (Level::KL1 as u8, number),
+                    _ => return Err(WarpSquareError::InvalidNotation(s.to_string())),
+                };
+
+                match number.parse::<u8>() {
+                    Ok(number @ 1..=6) => Level::from_u8(base + number - 1),
+                    _ => return Err(WarpSquareError::InvalidNotation(s.to_string())),
+                }
+            }
+        };
+
+        Ok(level)
+    }
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug, Hash)]
@@ -174,3 +291,30 @@ impl Square {
         }
     }
 }
+
+/// 파일, 랭크, Level 순서로 표기합니다. (예: a2W, z0QL1)
+impl fmt::Display for Square {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.file, self.rank, self.level)
+    }
+}
+
+impl FromStr for Square {
+    type Err = WarpSquareError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        let file = chars.next().and_then(File::from_char);
+        let rank = chars.next().and_then(|c| c.to_digit(10));
+
+        match (file, rank) {
+            (Some(file), Some(rank)) => Ok(Square::new(
+                Rank::from_u8(rank as u8),
+                file,
+                chars.as_str().parse()?,
+            )),
+            _ => Err(WarpSquareError::InvalidNotation(s.to_string())),
+        }
+    }
+}
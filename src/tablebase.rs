@@ -0,0 +1,902 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Read, Write},
+    path::Path,
+    thread,
+};
+
+use crate::{
+    attack_table,
+    bit_board::{BitBoard, BoardType},
+    board::Board,
+    error::WarpSquareError,
+    piece::PieceType,
+    square::{Color, Level, NUM_SQUARES},
+};
+
+const MAGIC: &[u8; 4] = b"WSTB";
+const VERSION: u8 = 1;
+/// 킹 두 개를 포함한 최대 기물 수
+pub const MAX_PIECES: usize = 4;
+
+/// 저장 값: 0은 무승부, 양수 d는 d수 만에 메이트하는 승리, 음수 -(d + 1)은 d수 만에 메이트당하는 패배
+const ILLEGAL: i16 = i16::MIN;
+const UNKNOWN: i16 = i16::MIN + 1;
+const DRAW: i16 = 0;
+/// 표를 만들 때 잡혀서 없는 기물의 Square 번호
+const CAPTURED: usize = usize::MAX;
+/// 잡는 수로 이기거나 비길 수 있어 질 수 없는 국면의 남은 수
+const NO_LOSS: u8 = u8::MAX;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum Wdl {
+    Win,
+    Draw,
+    Loss,
+}
+
+/// 현재 차례에서 본 결과
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct TablebaseEntry {
+    pub wdl: Wdl,
+    /// 메이트까지 남은 반수, 무승부는 0
+    pub distance: u32,
+}
+
+impl TablebaseEntry {
+    fn from_value(value: i16) -> Option<Self> {
+        match value {
+            ILLEGAL | UNKNOWN => None,
+            DRAW => Some(Self {
+                wdl: Wdl::Draw,
+                distance: 0,
+            }),
+            1.. => Some(Self {
+                wdl: Wdl::Win,
+                distance: value as u32,
+            }),
+            _ => Some(Self {
+                wdl: Wdl::Loss,
+                distance: (-value - 1) as u32,
+            }),
+        }
+    }
+}
+
+/// 킹 두 개를 뺀 기물 구성, (색, 기물 종류) 순서로 정렬되어 있습니다.
+type Signature = Vec<(Color, PieceType)>;
+
+fn normalize(pieces: &[(Color, PieceType)]) -> Signature {
+    let mut signature = pieces.to_vec();
+
+    signature.sort_by_key(|(color, piece_type)| (*color as u8, *piece_type as u8));
+
+    signature
+}
+
+/// 메인 보드는 제자리에 있고 공격 보드는 서로 다른 핀에 있는지 여부
+fn is_valid_board_set(board_set: &[(BoardType, Level); 7]) -> bool {
+    board_set
+        .iter()
+        .enumerate()
+        .all(|(index, (board_type, level))| {
+            let is_valid_level = match board_type {
+                BoardType::White => *level == Level::White,
+                BoardType::Neutral => *level == Level::Neutral,
+                BoardType::Black => *level == Level::Black,
+                _ => level.get_pin_number().is_some(),
+            };
+
+            *board_type as usize == index
+                && is_valid_level
+                && board_set[..index].iter().all(|(_, other)| other != level)
+        })
+}
+
+/// 공격 보드 배치 하나에서 적은 기물 엔딩의 승패와 메이트까지의 거리
+///
+/// 양쪽 모두 공격 보드를 움직이지 않는다고 가정한 결과입니다. 실제 게임에서는 빈 공격 보드를
+/// 움직여 차례를 넘길 수 있으므로 메이트가 없어질 수 있어, 탐색은 이 값을 평가로만 씁니다.
+/// 캐슬링, 앙파상, 50수 규칙은 고려하지 않고 폰은 지원하지 않습니다.
+pub struct Tablebase {
+    pub board_set: [(BoardType, Level); 7],
+    /// board_set의 보드 순서로 나열한 모든 Square, Level이 포함되어 있습니다.
+    squares: Vec<BitBoard>,
+    square_indices: HashMap<BitBoard, usize>,
+    /// Square 번호별 Level을 뺀 Square
+    columns: Vec<BitBoard>,
+    /// 보드 안의 Square 번호(0 ~ 59)별로 그 Square가 있는 모든 보드의 Square 번호
+    column_squares: Vec<Vec<usize>>,
+    tables: HashMap<Signature, Vec<i16>>,
+}
+
+impl Tablebase {
+    pub fn new(board_set: [(BoardType, Level); 7]) -> Self {
+        let squares: Vec<BitBoard> = board_set
+            .iter()
+            .flat_map(|(_, level)| {
+                level
+                    .get_bit_board()
+                    .iter()
+                    .map(|square| square | level.into_bit_board())
+            })
+            .collect();
+
+        let square_indices = squares
+            .iter()
+            .enumerate()
+            .map(|(index, square)| (*square, index))
+            .collect();
+
+        let columns: Vec<BitBoard> = squares.iter().map(BitBoard::remove_level).collect();
+        let mut column_squares = vec![Vec::new(); NUM_SQUARES];
+
+        for (square, column) in columns.iter().enumerate() {
+            column_squares[column.get_index()].push(square);
+        }
+
+        Self {
+            board_set,
+            squares,
+            square_indices,
+            columns,
+            column_squares,
+            tables: HashMap::new(),
+        }
+    }
+
+    /// 만들어 둔 기물 구성
+    pub fn get_signatures(&self) -> Vec<&[(Color, PieceType)]> {
+        self.tables
+            .keys()
+            .map(|signature| signature.as_slice())
+            .collect()
+    }
+
+    /// 킹 두 개와 pieces로 이루어진 표를 만듭니다. 기물을 잡아 넘어가는 작은 표도 함께 만듭니다.
+    pub fn generate(&mut self, pieces: &[(Color, PieceType)]) -> Result<(), WarpSquareError> {
+        let signature = normalize(pieces);
+
+        if signature.len() + 2 > MAX_PIECES
+            || signature
+                .iter()
+                .any(|(_, piece_type)| matches!(piece_type, PieceType::Pawn | PieceType::King))
+        {
+            return Err(WarpSquareError::UnsupportedTablebase);
+        }
+
+        if self.tables.contains_key(&signature) {
+            return Ok(());
+        }
+
+        for index in 0..signature.len() {
+            let mut sub_signature = signature.clone();
+            sub_signature.remove(index);
+
+            self.generate(&sub_signature)?;
+        }
+
+        let values = self.generate_table(&signature);
+        self.tables.insert(signature, values);
+
+        Ok(())
+    }
+
+    /// 차례, 백 킹, 흑 킹, signature 순서의 Square 번호로 만든 표의 위치
+    fn get_index(&self, turn: Color, squares: &[usize]) -> usize {
+        squares.iter().fold(turn as usize, |index, square| {
+            index * self.squares.len() + square
+        })
+    }
+
+    fn decode_index(&self, mut index: usize, squares: &mut [usize]) -> Color {
+        for square in squares.iter_mut().rev() {
+            *square = index % self.squares.len();
+            index /= self.squares.len();
+        }
+
+        match index {
+            0 => Color::White,
+            _ => Color::Black,
+        }
+    }
+
+    fn get_pieces(signature: &Signature) -> Signature {
+        [
+            (Color::White, PieceType::King),
+            (Color::Black, PieceType::King),
+        ]
+        .into_iter()
+        .chain(signature.iter().copied())
+        .collect()
+    }
+
+    /// squares의 기물로 모든 보드가 차 있는 Square 열, Board::ray_occupied와 같습니다.
+    fn get_ray_occupied(&self, squares: &[usize]) -> BitBoard {
+        let mut ray_occupied = BitBoard::EMPTY;
+
+        for square in squares.iter().filter(|square| **square != CAPTURED) {
+            let column = self.columns[*square];
+            let count = squares
+                .iter()
+                .filter(|other| **other != CAPTURED && self.columns[**other] == column)
+                .count();
+
+            if count == self.column_squares[column.get_index()].len() {
+                ray_occupied |= column;
+            }
+        }
+
+        ray_occupied
+    }
+
+    /// square의 기물이 공격하는 Square 열, Piece::compute_attacks와 같은 행마표를 씁니다.
+    fn get_attacks(
+        &self,
+        piece_type: PieceType,
+        square: usize,
+        ray_occupied: BitBoard,
+    ) -> BitBoard {
+        let column = self.columns[square];
+
+        match piece_type {
+            PieceType::Knight => attack_table::get_knight_attacks(column),
+            PieceType::Bishop => attack_table::get_bishop_attacks(column, ray_occupied),
+            PieceType::Rook => attack_table::get_rook_attacks(column, ray_occupied),
+            PieceType::Queen => attack_table::get_queen_attacks(column, ray_occupied),
+            PieceType::King => attack_table::get_king_attacks(column),
+            PieceType::Pawn => BitBoard::EMPTY,
+        }
+    }
+
+    /// color 색의 킹이 상대 기물에게 공격받는지 여부
+    fn is_check(&self, pieces: &[(Color, PieceType)], squares: &[usize], color: Color) -> bool {
+        let king = self.columns[squares[color as usize]];
+        let ray_occupied = self.get_ray_occupied(squares);
+
+        pieces
+            .iter()
+            .zip(squares.iter())
+            .any(|((piece_color, piece_type), square)| {
+                *piece_color != color
+                    && *square != CAPTURED
+                    && self
+                        .get_attacks(*piece_type, *square, ray_occupied)
+                        .contains(king)
+            })
+    }
+
+    /// turn 쪽의 합법적인 수마다 수를 둔 뒤의 배치와 잡힌 기물 번호를 f에 넘깁니다.
+    fn for_each_move(
+        &self,
+        pieces: &[(Color, PieceType)],
+        squares: &[usize],
+        turn: Color,
+        mut f: impl FnMut(&[usize], Option<usize>),
+    ) {
+        let ray_occupied = self.get_ray_occupied(squares);
+        let mut buffer = [CAPTURED; MAX_PIECES];
+        let next = &mut buffer[..squares.len()];
+
+        for (piece, (color, piece_type)) in pieces.iter().enumerate() {
+            if *color != turn {
+                continue;
+            }
+
+            for column in self
+                .get_attacks(*piece_type, squares[piece], ray_occupied)
+                .iter()
+            {
+                for destination in self.column_squares[column.get_index()].iter() {
+                    let occupant = squares.iter().position(|square| square == destination);
+
+                    if occupant.is_some_and(|occupant| pieces[occupant].0 == turn) {
+                        continue;
+                    }
+
+                    next.copy_from_slice(squares);
+                    next[piece] = *destination;
+
+                    if let Some(occupant) = occupant {
+                        next[occupant] = CAPTURED;
+                    }
+
+                    if !self.is_check(pieces, next, turn) {
+                        f(next, occupant);
+                    }
+                }
+            }
+        }
+    }
+
+    /// turn 차례 국면이 되도록 상대가 기물을 잡지 않고 둔 수의 직전 배치를 f에 넘깁니다.
+    ///
+    /// 폰이 없으면 행마가 대칭이므로 기물을 반대로 움직여 찾습니다.
+    fn for_each_unmove(
+        &self,
+        pieces: &[(Color, PieceType)],
+        squares: &[usize],
+        turn: Color,
+        mut f: impl FnMut(&[usize]),
+    ) {
+        let ray_occupied = self.get_ray_occupied(squares);
+        let mut buffer = [CAPTURED; MAX_PIECES];
+        let previous = &mut buffer[..squares.len()];
+
+        for (piece, (color, piece_type)) in pieces.iter().enumerate() {
+            if *color == turn {
+                continue;
+            }
+
+            for column in self
+                .get_attacks(*piece_type, squares[piece], ray_occupied)
+                .iter()
+            {
+                for source in self.column_squares[column.get_index()].iter() {
+                    if squares.contains(source) {
+                        continue;
+                    }
+
+                    previous.copy_from_slice(squares);
+                    previous[piece] = *source;
+
+                    // 직전 국면에서 차례가 아닌 쪽의 킹이 공격받으면 나올 수 없는 국면
+                    if !self.is_check(pieces, previous, turn) {
+                        f(previous);
+                    }
+                }
+            }
+        }
+    }
+
+    /// start부터 values 길이만큼의 국면에서 수를 세고 잡는 수의 결과를 모읍니다.
+    ///
+    /// 메이트와 잡는 수만으로 결과가 정해지는 국면을 (거리, 위치)로 반환합니다.
+    #[allow(clippy::type_complexity)]
+    fn initialize_table(
+        &self,
+        pieces: &[(Color, PieceType)],
+        sub_tables: &[Option<&[i16]>],
+        start: usize,
+        values: &mut [i16],
+        remaining: &mut [u8],
+        capture_losses: &mut [u16],
+    ) -> (Vec<(usize, usize)>, Vec<(usize, usize)>) {
+        let mut wins = Vec::new();
+        let mut losses = Vec::new();
+        let mut buffer = [0; MAX_PIECES];
+        let squares = &mut buffer[..pieces.len()];
+
+        for (offset, value) in values.iter_mut().enumerate() {
+            let index = start + offset;
+            let turn = self.decode_index(index, squares);
+
+            if (1..squares.len()).any(|i| squares[..i].contains(&squares[i]))
+                || self.is_check(pieces, squares, !turn)
+            {
+                continue;
+            }
+
+            let mut moves = 0;
+            let mut has_move = false;
+            let mut can_draw = false;
+            let mut best_win: Option<usize> = None;
+            let mut capture_loss = 0;
+
+            self.for_each_move(pieces, squares, turn, |next, captured| {
+                has_move = true;
+
+                let captured = match captured {
+                    Some(captured) => captured,
+                    None => {
+                        moves += 1;
+                        return;
+                    }
+                };
+
+                let mut child = [0; MAX_PIECES];
+
+                for (square, child_square) in next
+                    .iter()
+                    .filter(|square| **square != CAPTURED)
+                    .zip(child.iter_mut())
+                {
+                    *child_square = *square;
+                }
+
+                let child_value = match sub_tables[captured] {
+                    Some(table) => table[self.get_index(!turn, &child[..next.len() - 1])],
+                    None => DRAW,
+                };
+
+                match TablebaseEntry::from_value(child_value) {
+                    Some(TablebaseEntry {
+                        wdl: Wdl::Loss,
+                        distance,
+                    }) => {
+                        let win = distance as usize + 1;
+
+                        best_win = Some(best_win.map_or(win, |best| best.min(win)));
+                    }
+                    Some(TablebaseEntry {
+                        wdl: Wdl::Win,
+                        distance,
+                    }) => capture_loss = capture_loss.max(distance as usize + 1),
+                    _ => can_draw = true,
+                }
+            });
+
+            if !has_move {
+                if self.is_check(pieces, squares, turn) {
+                    *value = -1;
+                    losses.push((0, index));
+                } else {
+                    *value = DRAW;
+                }
+
+                continue;
+            }
+
+            *value = UNKNOWN;
+            capture_losses[offset] = capture_loss as u16;
+            remaining[offset] = match best_win.is_some() || can_draw {
+                true => NO_LOSS,
+                false => moves,
+            };
+
+            if let Some(best_win) = best_win {
+                wins.push((best_win, index));
+            } else if moves == 0 && !can_draw {
+                losses.push((capture_loss, index));
+            }
+        }
+
+        (wins, losses)
+    }
+
+    /// 메이트와 잡는 수로 정해지는 국면에서 시작해 거리 순서대로 직전 국면의 승패를 채웁니다.
+    ///
+    /// 국면마다 아직 상대의 승리로 정해지지 않은 수를 세어 두고, 0이 되면 패배로 정합니다.
+    fn generate_table(&self, signature: &Signature) -> Vec<i16> {
+        let pieces = Self::get_pieces(signature);
+        let size = 2 * self.squares.len().pow(pieces.len() as u32);
+
+        // 기물 번호별로 그 기물을 잡았을 때 넘어가는 표
+        let sub_tables: Vec<Option<&[i16]>> = (0..pieces.len())
+            .map(|piece| {
+                let mut sub_signature = signature.clone();
+                sub_signature.remove(piece.checked_sub(2)?);
+
+                self.tables.get(&sub_signature).map(Vec::as_slice)
+            })
+            .collect();
+
+        let mut values = vec![ILLEGAL; size];
+        let mut remaining = vec![0; size];
+        let mut capture_losses = vec![0; size];
+        // 거리별로 잡는 수만으로 결과가 정해지는 국면
+        let mut wins: Vec<Vec<usize>> = Vec::new();
+        let mut losses: Vec<Vec<usize>> = Vec::new();
+        let mut current = Vec::new();
+
+        let threads = thread::available_parallelism().map_or(1, |threads| threads.get());
+        let chunk_size = size.div_ceil(threads);
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = values
+                .chunks_mut(chunk_size)
+                .zip(remaining.chunks_mut(chunk_size))
+                .zip(capture_losses.chunks_mut(chunk_size))
+                .enumerate()
+                .map(|(chunk, ((values, remaining), capture_losses))| {
+                    let pieces = &pieces;
+                    let sub_tables = &sub_tables;
+
+                    scope.spawn(move || {
+                        self.initialize_table(
+                            pieces,
+                            sub_tables,
+                            chunk * chunk_size,
+                            values,
+                            remaining,
+                            capture_losses,
+                        )
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                let (chunk_wins, chunk_losses) = handle.join().unwrap();
+
+                for (results, chunk_results) in
+                    [(&mut wins, chunk_wins), (&mut losses, chunk_losses)]
+                {
+                    for (distance, index) in chunk_results {
+                        if results.len() <= distance {
+                            results.resize(distance + 1, Vec::new());
+                        }
+
+                        results[distance].push(index);
+                    }
+                }
+            }
+        });
+
+        if let Some(mates) = losses.first_mut() {
+            current = std::mem::take(mates);
+        }
+
+        let mut buffer = [0; MAX_PIECES];
+        let squares = &mut buffer[..pieces.len()];
+        let mut distance = 0;
+
+        while !current.is_empty() || distance < wins.len().max(losses.len()) {
+            let mut next = Vec::new();
+
+            for index in current {
+                let turn = self.decode_index(index, squares);
+                let is_loss = values[index] < 0;
+
+                self.for_each_unmove(&pieces, squares, turn, |previous| {
+                    let previous_index = self.get_index(!turn, previous);
+
+                    if values[previous_index] != UNKNOWN {
+                        return;
+                    }
+
+                    if is_loss {
+                        values[previous_index] = distance as i16 + 1;
+                        next.push(previous_index);
+                    } else if remaining[previous_index] != NO_LOSS {
+                        remaining[previous_index] -= 1;
+
+                        if remaining[previous_index] > 0 {
+                            return;
+                        }
+
+                        let capture_loss = capture_losses[previous_index] as usize;
+
+                        if capture_loss <= distance + 1 {
+                            values[previous_index] = -(distance as i16 + 1) - 1;
+                            next.push(previous_index);
+                        } else {
+                            if losses.len() <= capture_loss {
+                                losses.resize(capture_loss + 1, Vec::new());
+                            }
+
+                            losses[capture_loss].push(previous_index);
+                        }
+                    }
+                });
+            }
+
+            distance += 1;
+
+            for index in wins.get(distance).into_iter().flatten() {
+                if values[*index] == UNKNOWN {
+                    values[*index] = distance as i16;
+                    next.push(*index);
+                }
+            }
+
+            for index in losses.get(distance).into_iter().flatten() {
+                if values[*index] == UNKNOWN {
+                    values[*index] = -(distance as i16) - 1;
+                    next.push(*index);
+                }
+            }
+
+            current = next;
+        }
+
+        for value in values.iter_mut() {
+            if *value == UNKNOWN {
+                *value = DRAW;
+            }
+        }
+
+        values
+    }
+
+    /// 배치와 기물 구성이 같은 표가 있으면 현재 차례에서 본 결과를 반환합니다.
+    pub fn probe(&self, board: &Board, turn: Color) -> Option<TablebaseEntry> {
+        if board.board_set != self.board_set {
+            return None;
+        }
+
+        let mut pieces: Vec<_> = board.pieces().collect();
+
+        if pieces.len() < 2 || pieces.len() > MAX_PIECES {
+            return None;
+        }
+
+        pieces.sort_by_key(|piece| {
+            (
+                piece.piece_type != PieceType::King,
+                piece.color as u8,
+                piece.piece_type as u8,
+            )
+        });
+
+        let signature: Signature = pieces
+            .iter()
+            .skip(2)
+            .map(|piece| (piece.color, piece.piece_type))
+            .collect();
+        let table = self.tables.get(&signature)?;
+
+        if pieces[..2]
+            .iter()
+            .any(|piece| piece.piece_type != PieceType::King)
+        {
+            return None;
+        }
+
+        let squares = pieces
+            .iter()
+            .map(|piece| self.square_indices.get(&piece.position).copied())
+            .collect::<Option<Vec<usize>>>()?;
+
+        TablebaseEntry::from_value(table[self.get_index(turn, &squares)])
+    }
+
+    pub fn read(reader: &mut impl Read) -> io::Result<Self> {
+        let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message);
+        let read_u8 = |reader: &mut dyn Read| -> io::Result<u8> {
+            let mut buffer = [0; 1];
+            reader.read_exact(&mut buffer)?;
+
+            Ok(buffer[0])
+        };
+        let read_u32 = |reader: &mut dyn Read| -> io::Result<u32> {
+            let mut buffer = [0; 4];
+            reader.read_exact(&mut buffer)?;
+
+            Ok(u32::from_le_bytes(buffer))
+        };
+
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+
+        if &magic != MAGIC {
+            return Err(invalid("Not a tablebase"));
+        }
+
+        if read_u8(reader)? != VERSION {
+            return Err(invalid("Unsupported tablebase version"));
+        }
+
+        let mut board_set = Board::new().board_set;
+
+        for (board_type, level) in board_set.iter_mut() {
+            let board_type_byte = read_u8(reader)?;
+            let level_byte = read_u8(reader)?;
+
+            if board_type_byte != *board_type as u8
+                || !(Level::White as u8..=Level::KL6 as u8).contains(&level_byte)
+            {
+                return Err(invalid("Invalid board set"));
+            }
+
+            *level = Level::from_u8(level_byte);
+        }
+
+        if !is_valid_board_set(&board_set) {
+            return Err(invalid("Invalid board set"));
+        }
+
+        let mut tablebase = Self::new(board_set);
+
+        for _ in 0..read_u32(reader)? {
+            let mut signature = Signature::new();
+            let length = read_u8(reader)? as usize;
+
+            if length + 2 > MAX_PIECES {
+                return Err(invalid("Too many pieces in table"));
+            }
+
+            for _ in 0..length {
+                let color = match read_u8(reader)? {
+                    0 => Color::White,
+                    1 => Color::Black,
+                    _ => return Err(invalid("Invalid color")),
+                };
+                let piece_type = match read_u8(reader)? {
+                    1 => PieceType::Knight,
+                    2 => PieceType::Bishop,
+                    3 => PieceType::Rook,
+                    4 => PieceType::Queen,
+                    _ => return Err(invalid("Invalid piece type")),
+                };
+
+                signature.push((color, piece_type));
+            }
+
+            let size = tablebase
+                .squares
+                .len()
+                .checked_pow(length as u32 + 2)
+                .and_then(|size| size.checked_mul(2))
+                .ok_or_else(|| invalid("Invalid table"))?;
+
+            if read_u32(reader)? as usize != size || signature != normalize(&signature) {
+                return Err(invalid("Invalid table"));
+            }
+
+            let mut bytes = vec![0; size * 2];
+            reader.read_exact(&mut bytes)?;
+
+            let values = bytes
+                .chunks_exact(2)
+                .map(|value| i16::from_le_bytes([value[0], value[1]]))
+                .collect();
+
+            tablebase.tables.insert(signature, values);
+        }
+
+        Ok(tablebase)
+    }
+
+    /// MAGIC, VERSION, board_set, 표 개수 뒤에 표마다 기물 구성, 값 개수, 값(i16)이 이어집니다.
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+
+        for (board_type, level) in self.board_set.iter() {
+            writer.write_all(&[*board_type as u8, *level as u8])?;
+        }
+
+        writer.write_all(&(self.tables.len() as u32).to_le_bytes())?;
+
+        let mut tables: Vec<_> = self.tables.iter().collect();
+        tables.sort_by_key(|(signature, _)| {
+            let pieces: Vec<_> = signature
+                .iter()
+                .map(|(color, piece_type)| (*color as u8, *piece_type as u8))
+                .collect();
+
+            (pieces.len(), pieces)
+        });
+
+        for (signature, values) in tables {
+            writer.write_all(&[signature.len() as u8])?;
+
+            for (color, piece_type) in signature.iter() {
+                writer.write_all(&[*color as u8, *piece_type as u8])?;
+            }
+
+            writer.write_all(&(values.len() as u32).to_le_bytes())?;
+
+            for value in values.iter() {
+                writer.write_all(&value.to_le_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::read(&mut io::BufReader::new(fs::File::open(path)?))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = io::BufWriter::new(fs::File::create(path)?);
+
+        self.write(&mut writer)?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        castling::CastlingRights,
+        game::{Game, GameStatus},
+        piece_move::PieceMove,
+        square::Square,
+    };
+
+    fn header(board_set: &[(BoardType, Level); 7]) -> Vec<u8> {
+        let mut buffer = MAGIC.to_vec();
+
+        buffer.push(VERSION);
+
+        for (board_type, level) in board_set.iter() {
+            buffer.extend_from_slice(&[*board_type as u8, *level as u8]);
+        }
+
+        buffer
+    }
+
+    #[test]
+    fn write_read_round_trip() {
+        let mut tablebase = Tablebase::new(Board::new().board_set);
+
+        tablebase.generate(&[]).unwrap();
+
+        let mut buffer = Vec::new();
+        tablebase.write(&mut buffer).unwrap();
+
+        let read = Tablebase::read(&mut buffer.as_slice()).unwrap();
+
+        assert_eq!(read.board_set, tablebase.board_set);
+        assert_eq!(read.tables, tablebase.tables);
+    }
+
+    #[test]
+    fn rook_mate_in_one() {
+        let mut tablebase = Tablebase::new(Board::new().board_set);
+
+        tablebase
+            .generate(&[(Color::White, PieceType::Rook)])
+            .unwrap();
+
+        let mut game = Game::new();
+        game.board = Board::new();
+
+        for (square, piece_type, color) in [
+            ("a1W", PieceType::King, Color::White),
+            ("a1QL1", PieceType::King, Color::Black),
+            ("b2W", PieceType::Rook, Color::White),
+        ] {
+            let square = Square::from_str(square).unwrap();
+            game.board
+                .set_piece(BitBoard::from_square(&square), piece_type, color);
+        }
+
+        game.board.castling_rights = CastlingRights::empty();
+        game.board.update();
+
+        assert_eq!(
+            tablebase.probe(&game.board, game.turn),
+            Some(TablebaseEntry {
+                wdl: Wdl::Win,
+                distance: 1,
+            })
+        );
+
+        game.push_move(PieceMove::from_str("b2W-a2W").unwrap())
+            .unwrap();
+
+        assert_eq!(game.get_status(), GameStatus::Checkmate(Color::White));
+        assert_eq!(
+            tablebase.probe(&game.board, game.turn),
+            Some(TablebaseEntry {
+                wdl: Wdl::Loss,
+                distance: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn read_rejects_too_many_pieces() {
+        let mut buffer = header(&Board::new().board_set);
+
+        buffer.extend_from_slice(&1u32.to_le_bytes());
+        buffer.push(20);
+        buffer.extend_from_slice(&[0, PieceType::Rook as u8].repeat(20));
+
+        assert!(Tablebase::read(&mut buffer.as_slice()).is_err());
+    }
+
+    #[test]
+    fn read_rejects_invalid_board_set() {
+        let mut board_set = Board::new().board_set;
+        board_set[BoardType::BlackKing as usize].1 = Level::QL6;
+
+        let mut buffer = header(&board_set);
+        buffer.extend_from_slice(&0u32.to_le_bytes());
+
+        assert!(Tablebase::read(&mut buffer.as_slice()).is_err());
+
+        let mut buffer = header(&Board::new().board_set);
+        buffer[6] = 0;
+        buffer.extend_from_slice(&0u32.to_le_bytes());
+
+        assert!(Tablebase::read(&mut buffer.as_slice()).is_err());
+    }
+}
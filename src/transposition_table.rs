@@ -0,0 +1,126 @@
+use std::{
+    mem::size_of,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::piece_move::PieceMove;
+
+/// 저장된 점수가 실제 점수와 어떤 관계인지
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum Bound {
+    Exact = 1,
+    /// 실제 점수는 저장된 점수 이상
+    Lower,
+    /// 실제 점수는 저장된 점수 이하
+    Upper,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub struct TableEntry {
+    pub bound: Bound,
+    pub depth: u32,
+    pub score: i32,
+    pub best_move: Option<PieceMove>,
+}
+
+/// 해시와 데이터를 XOR해 두어 다른 스레드가 쓰다 만 항목을 걸러냅니다.
+#[derive(Default)]
+struct Slot {
+    key: AtomicU64,
+    data: AtomicU64,
+}
+
+/// 국면 해시로 찾는 고정 크기 탐색 결과 표, 여러 스레드가 잠금 없이 함께 씁니다.
+pub struct TranspositionTable {
+    slots: Vec<Slot>,
+}
+
+/// data의 하위 32비트: 수 24비트, Bound 2비트, 깊이 6비트, 상위 32비트: 점수
+const MOVE_BITS: u32 = 24;
+const BOUND_BITS: u32 = 2;
+const MAX_STORED_DEPTH: u32 = (1 << 6) - 1;
+
+impl TranspositionTable {
+    /// size_mb 메가바이트 안에 들어가는 만큼 항목을 만듭니다. 최소 한 개는 만듭니다.
+    pub fn new(size_mb: usize) -> Self {
+        let length = (size_mb * 1024 * 1024 / size_of::<Slot>()).max(1);
+
+        Self {
+            slots: (0..length).map(|_| Slot::default()).collect(),
+        }
+    }
+
+    /// 항목 개수
+    pub fn get_size(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// 새 게임을 시작할 때 모든 항목을 지웁니다.
+    pub fn clear(&self) {
+        for slot in self.slots.iter() {
+            slot.key.store(0, Ordering::Relaxed);
+            slot.data.store(0, Ordering::Relaxed);
+        }
+    }
+
+    fn get_slot(&self, hash: u64) -> &Slot {
+        &self.slots[(hash % self.slots.len() as u64) as usize]
+    }
+
+    pub fn probe(&self, hash: u64) -> Option<TableEntry> {
+        let slot = self.get_slot(hash);
+        let data = slot.data.load(Ordering::Relaxed);
+
+        if data == 0 || slot.key.load(Ordering::Relaxed) ^ data != hash {
+            return None;
+        }
+
+        decode_entry(data)
+    }
+
+    /// 비어 있거나, 같은 국면이거나, 더 얕게 탐색한 항목만 덮어씁니다.
+    pub fn store(&self, hash: u64, entry: &TableEntry) {
+        let slot = self.get_slot(hash);
+        let old_data = slot.data.load(Ordering::Relaxed);
+        let old_hash = slot.key.load(Ordering::Relaxed) ^ old_data;
+
+        let replace = match decode_entry(old_data) {
+            Some(old) => old_hash == hash || entry.depth >= old.depth,
+            None => true,
+        };
+
+        if replace {
+            let data = encode_entry(entry);
+
+            slot.key.store(hash ^ data, Ordering::Relaxed);
+            slot.data.store(data, Ordering::Relaxed);
+        }
+    }
+}
+
+fn encode_entry(entry: &TableEntry) -> u64 {
+    entry
+        .best_move
+        .as_ref()
+        .map_or(0, |piece_move| piece_move.to_bits() as u64)
+        | (entry.bound as u64) << MOVE_BITS
+        | (entry.depth.min(MAX_STORED_DEPTH) as u64) << (MOVE_BITS + BOUND_BITS)
+        | (entry.score as u32 as u64) << 32
+}
+
+/// 빈 항목은 Bound가 0이므로 None을 반환합니다.
+fn decode_entry(data: u64) -> Option<TableEntry> {
+    let bound = match (data >> MOVE_BITS) & 0x3 {
+        1 => Bound::Exact,
+        2 => Bound::Lower,
+        3 => Bound::Upper,
+        _ => return None,
+    };
+
+    Some(TableEntry {
+        bound,
+        depth: ((data >> (MOVE_BITS + BOUND_BITS)) & MAX_STORED_DEPTH as u64) as u32,
+        score: (data >> 32) as u32 as i32,
+        best_move: PieceMove::from_bits((data & ((1 << MOVE_BITS) - 1)) as u32),
+    })
+}
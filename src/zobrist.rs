@@ -0,0 +1,70 @@
+use crate::{
+    bit_board::{BitBoard, BoardType},
+    castling::CastlingRights,
+    piece::{PieceType, NUM_PIECES},
+    square::{Color, Level},
+};
+
+const NUM_LEVELS: usize = 16;
+const NUM_SQUARES: usize = 64;
+const NUM_BOARD_TYPES: usize = 7;
+
+const PIECE_KEYS: usize = 2 * NUM_PIECES * NUM_LEVELS * NUM_SQUARES;
+const BOARD_KEYS: usize = NUM_BOARD_TYPES * NUM_LEVELS;
+const CASTLING_KEYS: usize = 16;
+const EN_PASSANT_KEYS: usize = NUM_SQUARES;
+
+const BOARD_OFFSET: usize = PIECE_KEYS;
+const CASTLING_OFFSET: usize = BOARD_OFFSET + BOARD_KEYS;
+const EN_PASSANT_OFFSET: usize = CASTLING_OFFSET + CASTLING_KEYS;
+const SIDE_OFFSET: usize = EN_PASSANT_OFFSET + EN_PASSANT_KEYS;
+const NUM_KEYS: usize = SIDE_OFFSET + 1;
+
+/// splitmix64로 만든 고정된 난수 키, 실행할 때마다 같은 해시가 나옵니다.
+static KEYS: [u64; NUM_KEYS] = {
+    let mut keys = [0; NUM_KEYS];
+    let mut state: u64 = 0x5741_5250_5351_5541;
+    let mut i = 0;
+
+    while i < NUM_KEYS {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        keys[i] = z ^ (z >> 31);
+
+        i += 1;
+    }
+
+    keys
+};
+
+/// position(Level 포함)에 놓인 기물의 키
+pub fn piece(color: Color, piece_type: PieceType, position: BitBoard) -> u64 {
+    let index = ((color as usize * NUM_PIECES + piece_type as usize) * NUM_LEVELS
+        + position.get_level() as usize)
+        * NUM_SQUARES
+        + position.get_index();
+
+    KEYS[index]
+}
+
+/// 보드가 놓인 Level의 키
+pub fn board(board_type: BoardType, level: Level) -> u64 {
+    KEYS[BOARD_OFFSET + board_type as usize * NUM_LEVELS + level as usize]
+}
+
+pub fn castling(castling_rights: CastlingRights) -> u64 {
+    KEYS[CASTLING_OFFSET + castling_rights.bits() as usize]
+}
+
+/// 앙파상으로 건너뛴 Square의 키, Level 영역은 무시합니다.
+pub fn en_passant(square: BitBoard) -> u64 {
+    KEYS[EN_PASSANT_OFFSET + square.get_index()]
+}
+
+/// 흑 차례일 때 더하는 키
+pub fn side() -> u64 {
+    KEYS[SIDE_OFFSET]
+}